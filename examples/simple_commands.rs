@@ -2,7 +2,10 @@ use oresat_at86rf215_driver::registers::*;
 
 fn main() {
     // Create an RfCfg bitfield with some settings
-    let cfg = RfCfg::new().with_drv(3).with_irqp(true).with_irqmm(true);
+    let cfg = RfCfg::new()
+        .with_drv(DriverStrength::Ma8)
+        .with_irqp(true)
+        .with_irqmm(true);
 
     // Wrap it in the ReadWrite register wrapper
     let rf_cfg = ReadWrite::<RfCfg, 0x0006, 1>::new(cfg);
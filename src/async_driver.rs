@@ -0,0 +1,398 @@
+//! Async AT86RF215 Driver
+//!
+//! Mirrors [`Driver`](crate::driver::Driver) on top of `embedded-hal-async`,
+//! and additionally waits on the IRQ pin so TX/RX code can `.await` interrupt
+//! events instead of polling the IRQS registers.
+
+#[cfg(feature = "alloc")]
+use alloc::vec;
+
+use embedded_hal_async::delay::DelayNs;
+use embedded_hal_async::digital::Wait;
+use embedded_hal_async::spi::{Operation, SpiDevice};
+
+use crate::driver::{
+    DeviceInfo, Error, InitConfig, Wakeup, buffer_chunks, buffer_chunks_mut, detect_device,
+    rxfl_len, txfl_bytes,
+};
+use crate::radio::{Band, Radio};
+use crate::registers::*;
+
+/// Async AT86RF215 driver over an SPI device and the IRQ pin
+pub struct AsyncDriver<SPI, IRQ> {
+    spi: SPI,
+    irq: IRQ,
+    irq_active_low: bool,
+    max_transfer_len: usize,
+}
+
+impl<SPI: SpiDevice, IRQ: Wait> AsyncDriver<SPI, IRQ> {
+    /// Creates a driver expecting an active high IRQ (RF_CFG.IRQP = 0, the reset value).
+    pub fn new(spi: SPI, irq: IRQ) -> Self {
+        Self {
+            spi,
+            irq,
+            irq_active_low: false,
+            max_transfer_len: usize::MAX,
+        }
+    }
+
+    /// Limits every SPI transaction, header included, to `len` bytes.
+    ///
+    /// # Panics
+    /// Panics if `len` is shorter than [`MAX_COMMAND_LEN`].
+    pub fn set_max_transfer_len(&mut self, len: usize) {
+        assert!(
+            len >= MAX_COMMAND_LEN,
+            "maximum transfer length shorter than a register command"
+        );
+        self.max_transfer_len = len;
+    }
+
+    /// Maximum length of an SPI transaction, header included
+    pub fn max_transfer_len(&self) -> usize {
+        self.max_transfer_len
+    }
+
+    /// Consumes the driver and returns the underlying SPI device and IRQ pin.
+    pub fn release(self) -> (SPI, IRQ) {
+        (self.spi, self.irq)
+    }
+
+    /// Sets the IRQ polarity, must match RF_CFG.IRQP.
+    pub fn set_irq_active_low(&mut self, active_low: bool) {
+        self.irq_active_low = active_low;
+    }
+
+    /// Async version of [`Driver::init`](crate::driver::Driver::init).
+    ///
+    /// WAKEUP is polled, the IRQ pin is not used before the masks are set.
+    /// The IRQ polarity follows `config.cfg`.
+    pub async fn init(
+        &mut self,
+        radio: &mut Radio,
+        delay: &mut impl DelayNs,
+        config: &InitConfig,
+    ) -> Result<DeviceInfo, Error<SPI::Error, IRQ::Error>> {
+        radio.rf_rst.value.set_cmd(ChipResetCmd::Reset);
+        self.write(&radio.rf_rst).await?;
+        *radio = Radio::new();
+
+        let mut wakeup = Wakeup::default();
+        let mut waited = 0;
+        loop {
+            self.read(&mut radio.rf09_irqs).await?;
+            self.read(&mut radio.rf24_irqs).await?;
+            wakeup.update(radio);
+            let Some(band) = wakeup.pending() else {
+                break;
+            };
+            if waited >= config.wakeup_timeout_us {
+                return Err(Error::WakeupTimeout { band });
+            }
+            delay.delay_us(config.poll_interval_us).await;
+            waited = waited.saturating_add(config.poll_interval_us.max(1));
+        }
+
+        self.read(&mut radio.rf_pn).await?;
+        self.read(&mut radio.rf_vn).await?;
+        let device = detect_device(radio.rf_pn.value, radio.rf_vn.value)?;
+
+        for reg in config.apply(radio) {
+            self.write(&*reg).await?;
+            reg.mark_clean();
+        }
+        self.irq_active_low = config.cfg.irqp();
+        Ok(device)
+    }
+
+    /// Reads a single register from the chip and updates its value.
+    pub async fn read<R: Readable + ?Sized>(
+        &mut self,
+        reg: &mut R,
+    ) -> Result<(), Error<SPI::Error, IRQ::Error>> {
+        let mut buf = [0u8; MAX_COMMAND_LEN];
+        let len = reg.read_command_into(&mut buf)?;
+        self.spi
+            .transfer_in_place(&mut buf[..len])
+            .await
+            .map_err(Error::Spi)?;
+
+        // The first 2 bytes are clocked out while the header is sent
+        reg.set_from_bytes(&buf[HEADER_LEN..len]);
+        Ok(())
+    }
+
+    /// Writes the current value of a single register to the chip.
+    pub async fn write<W: Writable + ?Sized>(
+        &mut self,
+        reg: &W,
+    ) -> Result<(), Error<SPI::Error, IRQ::Error>> {
+        let mut buf = [0u8; MAX_COMMAND_LEN];
+        let len = reg.write_command_into(&mut buf)?;
+        self.spi.write(&buf[..len]).await.map_err(Error::Spi)
+    }
+
+    /// Reads all registers collected in `reads`, one transaction per contiguous block.
+    #[cfg(feature = "alloc")]
+    pub async fn read_bulk(
+        &mut self,
+        reads: &mut BulkReads<'_>,
+    ) -> Result<(), Error<SPI::Error, IRQ::Error>> {
+        let mut buf = vec![0x00; reads.max_command_len_within(self.max_transfer_len)];
+        self.read_bulk_with(reads, &mut buf).await
+    }
+
+    /// Like [`read_bulk`](Self::read_bulk), building each command in `buf`.
+    ///
+    /// `buf` must hold [`BulkReads::max_command_len`] bytes.
+    pub async fn read_bulk_with(
+        &mut self,
+        reads: &mut BulkReads<'_>,
+        buf: &mut [u8],
+    ) -> Result<(), Error<SPI::Error, IRQ::Error>> {
+        reads
+            .execute_within_async(self.max_transfer_len, buf, async |cmd: &mut [u8]| {
+                self.spi.transfer_in_place(cmd).await.map_err(Error::Spi)
+            })
+            .await
+    }
+
+    /// Writes all registers collected in `writes`, one transaction per contiguous block.
+    #[cfg(feature = "alloc")]
+    pub async fn write_bulk(
+        &mut self,
+        writes: &BulkWrites<'_>,
+    ) -> Result<(), Error<SPI::Error, IRQ::Error>> {
+        let mut buf = vec![0x00; writes.max_command_len_within(self.max_transfer_len)];
+        self.write_bulk_with(writes, &mut buf).await
+    }
+
+    /// Like [`write_bulk`](Self::write_bulk), building each command in `buf`.
+    ///
+    /// `buf` must hold [`BulkWrites::max_command_len`] bytes.
+    pub async fn write_bulk_with(
+        &mut self,
+        writes: &BulkWrites<'_>,
+        buf: &mut [u8],
+    ) -> Result<(), Error<SPI::Error, IRQ::Error>> {
+        writes
+            .execute_within_async(self.max_transfer_len, buf, async |cmd: &[u8]| {
+                self.spi.write(cmd).await.map_err(Error::Spi)
+            })
+            .await
+    }
+
+    /// Reads `buf.len()` bytes starting at `addr` using the chip's address auto-increment.
+    ///
+    /// Split into transactions of at most [`max_transfer_len`](Self::max_transfer_len) bytes.
+    /// Nothing is transferred if the range runs past 0x3FFF.
+    pub async fn read_buffer(
+        &mut self,
+        addr: u16,
+        buf: &mut [u8],
+    ) -> Result<(), Error<SPI::Error, IRQ::Error>> {
+        for (addr, chunk) in buffer_chunks_mut(addr, buf, self.max_transfer_len)? {
+            let header = generate_read_header(addr);
+            self.spi
+                .transaction(&mut [Operation::Write(&header), Operation::Read(chunk)])
+                .await
+                .map_err(Error::Spi)?;
+        }
+        Ok(())
+    }
+
+    /// Writes `data` starting at `addr` using the chip's address auto-increment.
+    ///
+    /// Split into transactions of at most [`max_transfer_len`](Self::max_transfer_len) bytes.
+    /// Nothing is transferred if the range runs past 0x3FFF.
+    pub async fn write_buffer(
+        &mut self,
+        addr: u16,
+        data: &[u8],
+    ) -> Result<(), Error<SPI::Error, IRQ::Error>> {
+        for (addr, chunk) in buffer_chunks(addr, data, self.max_transfer_len)? {
+            let header = generate_write_header(addr);
+            self.spi
+                .transaction(&mut [Operation::Write(&header), Operation::Write(chunk)])
+                .await
+                .map_err(Error::Spi)?;
+        }
+        Ok(())
+    }
+
+    /// Loads `frame` into the TX frame buffer of `band` and sets BBCn_TXFL.
+    pub async fn write_frame(
+        &mut self,
+        band: Band,
+        frame: &[u8],
+    ) -> Result<(), Error<SPI::Error, IRQ::Error>> {
+        let txfl = txfl_bytes(frame.len())?;
+
+        self.write_buffer(band.tx_frame_buffer(), frame).await?;
+        self.write_buffer(band.txfl_address(), &txfl).await
+    }
+
+    /// Reads the last received frame of `band` into `buf`, sized by BBCn_RXFL.
+    ///
+    /// Returns the frame length.
+    pub async fn read_frame(
+        &mut self,
+        band: Band,
+        buf: &mut [u8],
+    ) -> Result<usize, Error<SPI::Error, IRQ::Error>> {
+        let mut rxfl = [0u8; 2];
+        self.read_buffer(band.rxfl_address(), &mut rxfl).await?;
+        let len = rxfl_len(rxfl, buf.len())?;
+
+        self.read_buffer(band.rx_frame_buffer(), &mut buf[..len])
+            .await?;
+        Ok(len)
+    }
+
+    /// Waits until the IRQ pin is asserted.
+    ///
+    /// The IRQ is level triggered and stays asserted until every pending
+    /// IRQS register has been read.
+    pub async fn wait_irq(&mut self) -> Result<(), Error<SPI::Error, IRQ::Error>> {
+        if self.irq_active_low {
+            self.irq.wait_for_low().await.map_err(Error::Pin)
+        } else {
+            self.irq.wait_for_high().await.map_err(Error::Pin)
+        }
+    }
+
+    /// Waits for the IRQ pin and reads `irqs` until `done` accepts the status.
+    ///
+    /// Reading an IRQS register clears it, the last value read is left in `irqs`.
+    /// Interrupts from other IRQS registers keep the pin asserted, so they
+    /// should be masked or handled by the caller.
+    pub async fn wait_for<T, const ADDR: u16>(
+        &mut self,
+        irqs: &mut ReadOnly<T, ADDR, 1>,
+        mut done: impl FnMut(&T) -> bool,
+    ) -> Result<(), Error<SPI::Error, IRQ::Error>>
+    where
+        T: Copy + From<u8> + SideEffects,
+    {
+        loop {
+            self.wait_irq().await?;
+            self.read(irqs).await?;
+            if done(&irqs.value) {
+                return Ok(());
+            }
+        }
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::radio::Radio;
+    use embassy_futures::block_on;
+    use embedded_hal_mock::eh1::digital::{Mock as PinMock, State, Transaction as PinTransaction};
+    use embedded_hal_mock::eh1::spi::{Mock as SpiMock, Transaction};
+    use std::vec;
+
+    #[test]
+    fn test_read_write() {
+        let expectations = [
+            Transaction::transaction_start(),
+            Transaction::transfer_in_place(vec![0x01, 0x02, 0x00], vec![0x00, 0x00, 0x02]),
+            Transaction::transaction_end(),
+            Transaction::transaction_start(),
+            Transaction::write_vec(vec![0x81, 0x03, 0x03]),
+            Transaction::transaction_end(),
+        ];
+        let mut driver = AsyncDriver::new(SpiMock::new(&expectations), PinMock::new(&[]));
+        let mut radio = Radio::new();
+
+        block_on(driver.read(&mut radio.rf09.state)).unwrap();
+        assert_eq!(radio.rf09.state.value.state(), TransceiverState::TrxOff);
+
+        radio.rf09.cmd.value.set_cmd(TransceiverCmd::TxPrep);
+        block_on(driver.write(&radio.rf09.cmd)).unwrap();
+
+        let (mut spi, mut irq) = driver.release();
+        spi.done();
+        irq.done();
+    }
+
+    #[test]
+    fn test_wait_for_irq() {
+        let expectations = [
+            Transaction::transaction_start(),
+            Transaction::transfer_in_place(vec![0x00, 0x00, 0x00], vec![0x00, 0x00, 0x02]),
+            Transaction::transaction_end(),
+            Transaction::transaction_start(),
+            Transaction::transfer_in_place(vec![0x00, 0x00, 0x00], vec![0x00, 0x00, 0x01]),
+            Transaction::transaction_end(),
+        ];
+        let pin_expectations = [
+            PinTransaction::wait_for_state(State::High),
+            PinTransaction::wait_for_state(State::High),
+        ];
+        let mut driver =
+            AsyncDriver::new(SpiMock::new(&expectations), PinMock::new(&pin_expectations));
+        // RF09_IRQS
+        let mut irqs = ReadOnly::<RfnIrqs, 0x0000, 1>::new(RfnIrqs::new());
+
+        // The first interrupt is TRXRDY, keep waiting for WAKEUP
+        block_on(driver.wait_for(&mut irqs, |irqs| irqs.wakeup())).unwrap();
+        assert!(irqs.value.wakeup());
+
+        let (mut spi, mut irq) = driver.release();
+        spi.done();
+        irq.done();
+    }
+
+    #[test]
+    fn test_frame_round_trip() {
+        let expectations = [
+            Transaction::transaction_start(),
+            Transaction::write_vec(vec![0xA8, 0x00]),
+            Transaction::write_vec(vec![0x01]),
+            Transaction::transaction_end(),
+            Transaction::transaction_start(),
+            Transaction::write_vec(vec![0x83, 0x06]),
+            Transaction::write_vec(vec![0x01, 0x00]),
+            Transaction::transaction_end(),
+            Transaction::transaction_start(),
+            Transaction::write_vec(vec![0x03, 0x04]),
+            Transaction::read_vec(vec![0x01, 0x00]),
+            Transaction::transaction_end(),
+            Transaction::transaction_start(),
+            Transaction::write_vec(vec![0x20, 0x00]),
+            Transaction::read_vec(vec![0x02]),
+            Transaction::transaction_end(),
+        ];
+        let mut driver = AsyncDriver::new(SpiMock::new(&expectations), PinMock::new(&[]));
+
+        block_on(driver.write_frame(Band::Rf09, &[0x01])).unwrap();
+
+        let mut buf = [0u8; 4];
+        let len = block_on(driver.read_frame(Band::Rf09, &mut buf)).unwrap();
+        assert_eq!(&buf[..len], &[0x02]);
+
+        let (mut spi, mut irq) = driver.release();
+        spi.done();
+        irq.done();
+    }
+
+    #[test]
+    fn test_wait_irq_active_low() {
+        let pin_expectations = [PinTransaction::wait_for_state(State::Low)];
+        let mut driver = AsyncDriver::new(SpiMock::new(&[]), PinMock::new(&pin_expectations));
+
+        driver.set_irq_active_low(true);
+        block_on(driver.wait_irq()).unwrap();
+
+        let (mut spi, mut irq) = driver.release();
+        spi.done();
+        irq.done();
+    }
+}
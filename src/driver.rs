@@ -0,0 +1,970 @@
+//! AT86RF215 SPI Driver
+//!
+//! Executes the commands built by the register layer over an
+//! `embedded_hal::spi::SpiDevice` and decodes the responses back into the
+//! register values.
+
+#[cfg(feature = "alloc")]
+use alloc::vec;
+use core::convert::Infallible;
+
+use embedded_hal::delay::DelayNs;
+use embedded_hal::spi::{Operation, SpiDevice};
+
+use crate::radio::{Band, MAX_FRAME_LEN, Radio};
+use crate::registers::*;
+
+/// Driver errors
+///
+/// `PIN` is only used by drivers that wait on the IRQ pin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error<SPI, PIN = Infallible> {
+    /// Error reported by the underlying SPI device
+    Spi(SPI),
+
+    /// Error reported by the IRQ pin
+    Pin(PIN),
+
+    /// Frame is longer than the frame buffer
+    FrameTooLong { len: usize },
+
+    /// Frame or command does not fit in the caller's buffer
+    BufferTooSmall { len: usize, capacity: usize },
+
+    /// Buffer access of `len` bytes at `addr` runs past the end of the
+    /// address space
+    AddressOutOfRange { addr: u16, len: usize },
+
+    /// The transceiver did not report WAKEUP after a reset in time
+    WakeupTimeout { band: Band },
+
+    /// RF_PN or RF_VN identify a device the driver does not support
+    UnsupportedDevice { pn: u8, vn: u8 },
+}
+
+impl<SPI, PIN> From<BufferTooSmall> for Error<SPI, PIN> {
+    fn from(err: BufferTooSmall) -> Self {
+        Self::BufferTooSmall {
+            len: err.required,
+            capacity: err.available,
+        }
+    }
+}
+
+/// Checks a TX frame length and encodes it as BBCn_TXFL.
+pub(crate) fn txfl_bytes<SPI, PIN>(len: usize) -> Result<[u8; 2], Error<SPI, PIN>> {
+    if len > MAX_FRAME_LEN {
+        return Err(Error::FrameTooLong { len });
+    }
+    Ok(BbcnTxfl::new()
+        .with_txfl(len as u16)
+        .into_bits()
+        .to_le_bytes())
+}
+
+/// Decodes BBCn_RXFL and checks the frame fits in a buffer of `capacity` bytes.
+pub(crate) fn rxfl_len<SPI, PIN>(
+    bytes: [u8; 2],
+    capacity: usize,
+) -> Result<usize, Error<SPI, PIN>> {
+    let len = BbcnRxfl::from_bits(u16::from_le_bytes(bytes)).rxfl() as usize;
+    if len > capacity {
+        return Err(Error::BufferTooSmall { len, capacity });
+    }
+    Ok(len)
+}
+
+/// Checks `len` bytes at `addr` lie within the address space.
+fn check_buffer_range<SPI, PIN>(addr: u16, len: usize) -> Result<(), Error<SPI, PIN>> {
+    match (addr as usize).checked_add(len) {
+        Some(end) if end <= ADDRESS_SPACE_LEN => Ok(()),
+        _ => Err(Error::AddressOutOfRange { addr, len }),
+    }
+}
+
+/// Address of the chunk at `offset` from `addr`, within the checked range
+fn chunk_address(addr: u16, offset: usize) -> u16 {
+    u16::try_from(offset)
+        .ok()
+        .and_then(|offset| addr.checked_add(offset))
+        .expect("buffer range checked")
+}
+
+/// Splits `data` at `addr` into chunks fitting a transfer of `max_len` bytes
+/// with their header, along with the address of each chunk.
+///
+/// Fails if `data` runs past the end of the address space.
+pub(crate) fn buffer_chunks<SPI, PIN>(
+    addr: u16,
+    data: &[u8],
+    max_len: usize,
+) -> Result<impl Iterator<Item = (u16, &[u8])>, Error<SPI, PIN>> {
+    check_buffer_range(addr, data.len())?;
+    let chunk_len = max_len - HEADER_LEN;
+    Ok(data
+        .chunks(chunk_len)
+        .enumerate()
+        .map(move |(i, chunk)| (chunk_address(addr, i * chunk_len), chunk)))
+}
+
+/// Mutable version of [`buffer_chunks`].
+pub(crate) fn buffer_chunks_mut<SPI, PIN>(
+    addr: u16,
+    buf: &mut [u8],
+    max_len: usize,
+) -> Result<impl Iterator<Item = (u16, &mut [u8])>, Error<SPI, PIN>> {
+    check_buffer_range(addr, buf.len())?;
+    let chunk_len = max_len - HEADER_LEN;
+    Ok(buf
+        .chunks_mut(chunk_len)
+        .enumerate()
+        .map(move |(i, chunk)| (chunk_address(addr, i * chunk_len), chunk)))
+}
+
+/// Device versions (RF_VN) supported by the driver
+pub const SUPPORTED_VERSIONS: [u8; 2] = [0x01, 0x03];
+
+/// Settings programmed by [`Driver::init`]
+///
+/// The defaults are the reset values, with every IRQ masked.
+#[derive(Debug, Clone, Copy)]
+pub struct InitConfig {
+    /// Pad driver strength and IRQ pin behaviour
+    pub cfg: RfCfg,
+
+    /// Chip mode, enabling the basebands or the I/Q interface
+    pub chip_mode: ChipMode,
+
+    /// IRQs enabled on the transceivers
+    pub rf09_irqm: RfnIrqm,
+    pub rf24_irqm: RfnIrqm,
+
+    /// IRQs enabled on the basebands
+    pub bbc0_irqm: BbcnIrqm,
+    pub bbc1_irqm: BbcnIrqm,
+
+    /// Time to wait for WAKEUP after the reset, in microseconds
+    pub wakeup_timeout_us: u32,
+
+    /// Delay between two polls of the IRQS registers, in microseconds
+    pub poll_interval_us: u32,
+}
+
+impl Default for InitConfig {
+    fn default() -> Self {
+        Self {
+            cfg: RfCfg::reset(),
+            chip_mode: ChipMode::BasebandMode,
+            rf09_irqm: RfnIrqm::reset(),
+            rf24_irqm: RfnIrqm::reset(),
+            bbc0_irqm: BbcnIrqm::reset(),
+            bbc1_irqm: BbcnIrqm::reset(),
+            wakeup_timeout_us: 10_000,
+            poll_interval_us: 100,
+        }
+    }
+}
+
+impl InitConfig {
+    /// Sets the common registers of `radio` and returns them in write order.
+    pub(crate) fn apply<'a>(&self, radio: &'a mut Radio) -> [&'a mut dyn Writable; 6] {
+        radio.rf_cfg.value = self.cfg;
+        radio.rf_iqifc1.value.set_chpm(self.chip_mode);
+        radio.rf09.irqm.value = self.rf09_irqm;
+        radio.rf24.irqm.value = self.rf24_irqm;
+        radio.bbc0.irqm.value = self.bbc0_irqm;
+        radio.bbc1.irqm.value = self.bbc1_irqm;
+        [
+            &mut radio.rf_cfg,
+            &mut radio.rf_iqifc1,
+            &mut radio.rf09.irqm,
+            &mut radio.rf24.irqm,
+            &mut radio.bbc0.irqm,
+            &mut radio.bbc1.irqm,
+        ]
+    }
+}
+
+/// Device detected by [`Driver::init`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeviceInfo {
+    pub part: DevicePartNumber,
+
+    /// Version number, one of [`SUPPORTED_VERSIONS`]
+    pub version: u8,
+}
+
+impl DeviceInfo {
+    /// Whether the device has the BBC0 and BBC1 basebands, the AT86RF215IQ
+    /// only has the I/Q interface.
+    pub const fn has_baseband(&self) -> bool {
+        !matches!(self.part, DevicePartNumber::AT86RF215IQ)
+    }
+}
+
+/// Checks RF_PN and RF_VN identify a supported device.
+pub(crate) fn detect_device<SPI, PIN>(pn: RfPn, vn: RfVn) -> Result<DeviceInfo, Error<SPI, PIN>> {
+    let unsupported = Error::UnsupportedDevice {
+        pn: pn.into_bits(),
+        vn: vn.into_bits(),
+    };
+    if matches!(pn.pn(), DevicePartNumber::Unknown(_)) || !SUPPORTED_VERSIONS.contains(&vn.vn()) {
+        return Err(unsupported);
+    }
+    Ok(DeviceInfo {
+        part: pn.pn(),
+        version: vn.vn(),
+    })
+}
+
+/// Transceivers that reported WAKEUP since the reset
+#[derive(Debug, Default)]
+pub(crate) struct Wakeup {
+    rf09: bool,
+    rf24: bool,
+}
+
+impl Wakeup {
+    /// Records the WAKEUP flags just read into `radio`.
+    pub(crate) fn update(&mut self, radio: &Radio) {
+        self.rf09 |= radio.rf09_irqs.value.wakeup();
+        self.rf24 |= radio.rf24_irqs.value.wakeup();
+    }
+
+    /// First transceiver still waiting for WAKEUP
+    pub(crate) fn pending(&self) -> Option<Band> {
+        match (self.rf09, self.rf24) {
+            (false, _) => Some(Band::Rf09),
+            (true, false) => Some(Band::Rf24),
+            (true, true) => None,
+        }
+    }
+}
+
+/// Blocking AT86RF215 driver over an SPI device
+///
+/// Each register or block access is issued as a single SPI transaction,
+/// the chip select is held asserted for the header and all data bytes.
+pub struct Driver<SPI> {
+    spi: SPI,
+    max_transfer_len: usize,
+}
+
+impl<SPI: SpiDevice> Driver<SPI> {
+    pub fn new(spi: SPI) -> Self {
+        Self {
+            spi,
+            max_transfer_len: usize::MAX,
+        }
+    }
+
+    /// Limits every SPI transaction, header included, to `len` bytes.
+    ///
+    /// Longer bulk and buffer accesses are split into several transactions,
+    /// e.g. for the 4096 byte spidev default or a 255 byte DMA limit.
+    ///
+    /// # Panics
+    /// Panics if `len` is shorter than [`MAX_COMMAND_LEN`].
+    pub fn set_max_transfer_len(&mut self, len: usize) {
+        assert!(
+            len >= MAX_COMMAND_LEN,
+            "maximum transfer length shorter than a register command"
+        );
+        self.max_transfer_len = len;
+    }
+
+    /// Maximum length of an SPI transaction, header included
+    pub fn max_transfer_len(&self) -> usize {
+        self.max_transfer_len
+    }
+
+    /// Consumes the driver and returns the underlying SPI device.
+    pub fn release(self) -> SPI {
+        self.spi
+    }
+
+    /// Resets the chip and programs the common registers from `config`.
+    ///
+    /// Waits for WAKEUP on both transceivers, polling the IRQS registers
+    /// every `config.poll_interval_us`, then checks RF_PN and RF_VN before
+    /// writing RF_CFG, the chip mode and the IRQ masks.
+    ///
+    /// `radio` is reset along with the chip and receives the values read and
+    /// written, the programmed registers are left clean.
+    pub fn init(
+        &mut self,
+        radio: &mut Radio,
+        delay: &mut impl DelayNs,
+        config: &InitConfig,
+    ) -> Result<DeviceInfo, Error<SPI::Error>> {
+        radio.rf_rst.value.set_cmd(ChipResetCmd::Reset);
+        self.write(&radio.rf_rst)?;
+        *radio = Radio::new();
+
+        let mut wakeup = Wakeup::default();
+        let mut waited = 0;
+        loop {
+            self.read(&mut radio.rf09_irqs)?;
+            self.read(&mut radio.rf24_irqs)?;
+            wakeup.update(radio);
+            let Some(band) = wakeup.pending() else {
+                break;
+            };
+            if waited >= config.wakeup_timeout_us {
+                return Err(Error::WakeupTimeout { band });
+            }
+            delay.delay_us(config.poll_interval_us);
+            waited = waited.saturating_add(config.poll_interval_us.max(1));
+        }
+
+        self.read(&mut radio.rf_pn)?;
+        self.read(&mut radio.rf_vn)?;
+        let device = detect_device(radio.rf_pn.value, radio.rf_vn.value)?;
+
+        for reg in config.apply(radio) {
+            self.write(&*reg)?;
+            reg.mark_clean();
+        }
+        Ok(device)
+    }
+
+    /// Reads a single register from the chip and updates its value.
+    pub fn read<R: Readable + ?Sized>(&mut self, reg: &mut R) -> Result<(), Error<SPI::Error>> {
+        let mut buf = [0u8; MAX_COMMAND_LEN];
+        let len = reg.read_command_into(&mut buf)?;
+        self.spi
+            .transfer_in_place(&mut buf[..len])
+            .map_err(Error::Spi)?;
+
+        // The first 2 bytes are clocked out while the header is sent
+        reg.set_from_bytes(&buf[HEADER_LEN..len]);
+        Ok(())
+    }
+
+    /// Writes the current value of a single register to the chip.
+    pub fn write<W: Writable + ?Sized>(&mut self, reg: &W) -> Result<(), Error<SPI::Error>> {
+        let mut buf = [0u8; MAX_COMMAND_LEN];
+        let len = reg.write_command_into(&mut buf)?;
+        self.spi.write(&buf[..len]).map_err(Error::Spi)
+    }
+
+    /// Reads all registers collected in `reads`, one transaction per contiguous block.
+    #[cfg(feature = "alloc")]
+    pub fn read_bulk(&mut self, reads: &mut BulkReads<'_>) -> Result<(), Error<SPI::Error>> {
+        let mut buf = vec![0x00; reads.max_command_len_within(self.max_transfer_len)];
+        self.read_bulk_with(reads, &mut buf)
+    }
+
+    /// Like [`read_bulk`](Self::read_bulk), building each command in `buf`.
+    ///
+    /// `buf` must hold [`BulkReads::max_command_len`] bytes.
+    pub fn read_bulk_with(
+        &mut self,
+        reads: &mut BulkReads<'_>,
+        buf: &mut [u8],
+    ) -> Result<(), Error<SPI::Error>> {
+        reads.execute_within(self.max_transfer_len, buf, |cmd| {
+            self.spi.transfer_in_place(cmd).map_err(Error::Spi)
+        })
+    }
+
+    /// Writes all registers collected in `writes`, one transaction per contiguous block.
+    #[cfg(feature = "alloc")]
+    pub fn write_bulk(&mut self, writes: &BulkWrites<'_>) -> Result<(), Error<SPI::Error>> {
+        let mut buf = vec![0x00; writes.max_command_len_within(self.max_transfer_len)];
+        self.write_bulk_with(writes, &mut buf)
+    }
+
+    /// Like [`write_bulk`](Self::write_bulk), building each command in `buf`.
+    ///
+    /// `buf` must hold [`BulkWrites::max_command_len`] bytes.
+    pub fn write_bulk_with(
+        &mut self,
+        writes: &BulkWrites<'_>,
+        buf: &mut [u8],
+    ) -> Result<(), Error<SPI::Error>> {
+        writes.execute_within(self.max_transfer_len, buf, |cmd| {
+            self.spi.write(cmd).map_err(Error::Spi)
+        })
+    }
+
+    /// Reads `buf.len()` bytes starting at `addr` using the chip's address auto-increment.
+    ///
+    /// Split into transactions of at most [`max_transfer_len`](Self::max_transfer_len) bytes.
+    /// Nothing is transferred if the range runs past 0x3FFF.
+    pub fn read_buffer(&mut self, addr: u16, buf: &mut [u8]) -> Result<(), Error<SPI::Error>> {
+        for (addr, chunk) in buffer_chunks_mut(addr, buf, self.max_transfer_len)? {
+            let header = generate_read_header(addr);
+            self.spi
+                .transaction(&mut [Operation::Write(&header), Operation::Read(chunk)])
+                .map_err(Error::Spi)?;
+        }
+        Ok(())
+    }
+
+    /// Writes `data` starting at `addr` using the chip's address auto-increment.
+    ///
+    /// Split into transactions of at most [`max_transfer_len`](Self::max_transfer_len) bytes.
+    /// Nothing is transferred if the range runs past 0x3FFF.
+    pub fn write_buffer(&mut self, addr: u16, data: &[u8]) -> Result<(), Error<SPI::Error>> {
+        for (addr, chunk) in buffer_chunks(addr, data, self.max_transfer_len)? {
+            let header = generate_write_header(addr);
+            self.spi
+                .transaction(&mut [Operation::Write(&header), Operation::Write(chunk)])
+                .map_err(Error::Spi)?;
+        }
+        Ok(())
+    }
+
+    /// Loads `frame` into the TX frame buffer of `band` and sets BBCn_TXFL.
+    ///
+    /// Frames longer than [`MAX_FRAME_LEN`] are rejected before anything is written.
+    pub fn write_frame(&mut self, band: Band, frame: &[u8]) -> Result<(), Error<SPI::Error>> {
+        let txfl = txfl_bytes(frame.len())?;
+
+        self.write_buffer(band.tx_frame_buffer(), frame)?;
+        self.write_buffer(band.txfl_address(), &txfl)
+    }
+
+    /// Reads the last received frame of `band` into `buf`, sized by BBCn_RXFL.
+    ///
+    /// Returns the frame length. If the frame is longer than `buf` nothing is
+    /// read and [`Error::BufferTooSmall`] is returned.
+    pub fn read_frame(&mut self, band: Band, buf: &mut [u8]) -> Result<usize, Error<SPI::Error>> {
+        let mut rxfl = [0u8; 2];
+        self.read_buffer(band.rxfl_address(), &mut rxfl)?;
+        let len = rxfl_len(rxfl, buf.len())?;
+
+        self.read_buffer(band.rx_frame_buffer(), &mut buf[..len])?;
+        Ok(len)
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::radio::{BBC0_FBRXS, BBC0_FBTXS, Radio};
+    use embedded_hal_mock::eh1::delay::NoopDelay;
+    use embedded_hal_mock::eh1::spi::{Mock, Transaction};
+    use std::vec;
+
+    #[test]
+    fn test_read_register() {
+        let expectations = [
+            Transaction::transaction_start(),
+            Transaction::transfer_in_place(vec![0x00, 0x0D, 0x00], vec![0x00, 0x00, 0x34]),
+            Transaction::transaction_end(),
+        ];
+        let mut driver = Driver::new(Mock::new(&expectations));
+        let mut radio = Radio::new();
+
+        driver.read(&mut radio.rf_pn).unwrap();
+        assert_eq!(radio.rf_pn.value.pn(), DevicePartNumber::AT86RF215);
+
+        driver.release().done();
+    }
+
+    #[test]
+    fn test_write_register() {
+        let expectations = [
+            Transaction::transaction_start(),
+            Transaction::write_vec(vec![0x81, 0x03, 0x02]),
+            Transaction::transaction_end(),
+        ];
+        let mut driver = Driver::new(Mock::new(&expectations));
+        let mut radio = Radio::new();
+
+        radio.rf09.cmd.value.set_cmd(TransceiverCmd::TrxOff);
+        driver.write(&radio.rf09.cmd).unwrap();
+
+        driver.release().done();
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_read_bulk() {
+        // rf_cfg and rf_clko are contiguous, rf09_state is a separate block
+        let expectations = [
+            Transaction::transaction_start(),
+            Transaction::transfer_in_place(
+                vec![0x00, 0x06, 0x00, 0x00],
+                vec![0x00, 0x00, 0x0F, 0x09],
+            ),
+            Transaction::transaction_end(),
+            Transaction::transaction_start(),
+            Transaction::transfer_in_place(vec![0x01, 0x02, 0x00], vec![0x00, 0x00, 0x05]),
+            Transaction::transaction_end(),
+        ];
+        let mut driver = Driver::new(Mock::new(&expectations));
+        let mut radio = Radio::new();
+
+        let mut reads = BulkReads::new();
+        reads.add(&mut radio.rf_clko);
+        reads.add(&mut radio.rf09.state);
+        reads.add(&mut radio.rf_cfg);
+        driver.read_bulk(&mut reads).unwrap();
+
+        assert_eq!(radio.rf_cfg.value.into_bits(), 0x0F);
+        assert_eq!(radio.rf_clko.value.os(), ClockOutput::Mhz26);
+        assert_eq!(radio.rf_clko.value.drv(), DriverStrength::Ma4);
+        assert_eq!(radio.rf09.state.value.state(), TransceiverState::Rx);
+
+        driver.release().done();
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_write_bulk() {
+        let expectations = [
+            Transaction::transaction_start(),
+            Transaction::write_vec(vec![0x80, 0x06, 0x0B, 0x09]),
+            Transaction::transaction_end(),
+        ];
+        let mut driver = Driver::new(Mock::new(&expectations));
+        let mut radio = Radio::new();
+
+        radio.rf_cfg.value = radio
+            .rf_cfg
+            .value
+            .with_drv(DriverStrength::Ma8)
+            .with_irqmm(true);
+        radio.rf_clko.value = radio
+            .rf_clko
+            .value
+            .with_os(ClockOutput::Mhz26)
+            .with_drv(DriverStrength::Ma4);
+
+        let mut writes = BulkWrites::new();
+        writes.add(&mut radio.rf_clko);
+        writes.add(&mut radio.rf_cfg);
+        driver.write_bulk(&writes).unwrap();
+
+        driver.release().done();
+    }
+
+    #[test]
+    fn test_read_bulk_with_buffer() {
+        // rf_cfg and rf_clko are contiguous, so are rf09_cs and rf09_ccf0
+        let expectations = [
+            Transaction::transaction_start(),
+            Transaction::transfer_in_place(
+                vec![0x00, 0x06, 0x00, 0x00],
+                vec![0x00, 0x00, 0x0F, 0x09],
+            ),
+            Transaction::transaction_end(),
+            Transaction::transaction_start(),
+            Transaction::transfer_in_place(
+                vec![0x01, 0x04, 0x00, 0x00, 0x00],
+                vec![0x00, 0x00, 0x30, 0xA0, 0x8C],
+            ),
+            Transaction::transaction_end(),
+        ];
+        let mut driver = Driver::new(Mock::new(&expectations));
+        let mut radio = Radio::new();
+
+        let mut reads = BulkReads::new();
+        reads.add(&mut radio.rf_clko);
+        reads.add(&mut radio.rf_cfg);
+        reads.add(&mut radio.rf09.cs);
+        reads.add(&mut radio.rf09.ccf0);
+
+        let mut small = [0u8; 3];
+        assert_eq!(
+            driver.read_bulk_with(&mut reads, &mut small),
+            Err(Error::BufferTooSmall {
+                len: 4,
+                capacity: 3
+            })
+        );
+
+        assert_eq!(reads.max_command_len(), 5);
+        let mut buf = [0u8; 5];
+        driver.read_bulk_with(&mut reads, &mut buf).unwrap();
+        drop(reads);
+
+        assert_eq!(radio.rf_cfg.value.into_bits(), 0x0F);
+        assert_eq!(radio.rf_clko.value.into_bits(), 0x09);
+        assert_eq!(radio.rf09.cs.value.into_bits(), 0x30);
+        assert_eq!(radio.rf09.ccf0.value.into_bits(), 0x8CA0);
+
+        driver.release().done();
+    }
+
+    #[test]
+    fn test_write_bulk_with_buffer() {
+        let expectations = [
+            Transaction::transaction_start(),
+            Transaction::write_vec(vec![0x80, 0x06, 0x08, 0x09]),
+            Transaction::transaction_end(),
+        ];
+        let mut driver = Driver::new(Mock::new(&expectations));
+        let mut radio = Radio::new();
+
+        radio.rf_cfg.value = radio.rf_cfg.value.with_irqmm(true);
+        radio.rf_clko.value = radio
+            .rf_clko
+            .value
+            .with_os(ClockOutput::Mhz26)
+            .with_drv(DriverStrength::Ma4);
+
+        let mut writes = BulkWrites::new();
+        writes.add(&mut radio.rf_cfg);
+        writes.add(&mut radio.rf_clko);
+
+        let mut small = [0u8; 3];
+        assert_eq!(
+            driver.write_bulk_with(&writes, &mut small),
+            Err(Error::BufferTooSmall {
+                len: 4,
+                capacity: 3
+            })
+        );
+
+        let mut buf = [0u8; 4];
+        driver.write_bulk_with(&writes, &mut buf).unwrap();
+
+        driver.release().done();
+    }
+
+    #[test]
+    fn test_buffer_access() {
+        let expectations = [
+            Transaction::transaction_start(),
+            Transaction::write_vec(vec![0xA0, 0x00]),
+            Transaction::write_vec(vec![0x01, 0x02, 0x03]),
+            Transaction::transaction_end(),
+            Transaction::transaction_start(),
+            Transaction::write_vec(vec![0x30, 0x00]),
+            Transaction::read_vec(vec![0x04, 0x05]),
+            Transaction::transaction_end(),
+        ];
+        let mut driver = Driver::new(Mock::new(&expectations));
+
+        driver.write_buffer(0x2000, &[0x01, 0x02, 0x03]).unwrap();
+
+        let mut buf = [0u8; 2];
+        driver.read_buffer(0x3000, &mut buf).unwrap();
+        assert_eq!(buf, [0x04, 0x05]);
+
+        driver.release().done();
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_read_bulk_split_at_max_transfer_len() {
+        // CS..AGCC fill a 10 byte transfer, AGCS follows in a second one
+        let expectations = [
+            Transaction::transaction_start(),
+            Transaction::transfer_in_place(
+                vec![0x01, 0x04, 0, 0, 0, 0, 0, 0, 0, 0],
+                vec![0, 0, 0x30, 0xA0, 0x8C, 0x05, 0x00, 0x04, 0x41, 0x03],
+            ),
+            Transaction::transaction_end(),
+            Transaction::transaction_start(),
+            Transaction::transfer_in_place(vec![0x01, 0x0C, 0x00], vec![0x00, 0x00, 0x66]),
+            Transaction::transaction_end(),
+        ];
+        let mut driver = Driver::new(Mock::new(&expectations));
+        driver.set_max_transfer_len(10);
+        let mut radio = Radio::new();
+
+        let mut reads = BulkReads::new();
+        let rf09 = &mut radio.rf09;
+        reads.add(&mut rf09.cs);
+        reads.add(&mut rf09.ccf0);
+        reads.add(&mut rf09.cnl);
+        reads.add(&mut rf09.cnm);
+        reads.add(&mut rf09.rxbwc);
+        reads.add(&mut rf09.rxdfe);
+        reads.add(&mut rf09.agcc);
+        reads.add(&mut rf09.agcs);
+        driver.read_bulk(&mut reads).unwrap();
+
+        assert_eq!(radio.rf09.cs.value.into_bits(), 0x30);
+        assert_eq!(radio.rf09.ccf0.value.into_bits(), 0x8CA0);
+        assert_eq!(radio.rf09.cnl.value.into_bits(), 0x05);
+        assert_eq!(radio.rf09.rxbwc.value.into_bits(), 0x04);
+        assert_eq!(radio.rf09.agcc.value.into_bits(), 0x03);
+        assert_eq!(radio.rf09.agcs.value.into_bits(), 0x66);
+
+        driver.release().done();
+    }
+
+    #[test]
+    fn test_buffer_split_at_max_transfer_len() {
+        let frame: [u8; 25] = core::array::from_fn(|i| i as u8);
+        let expectations = [
+            Transaction::transaction_start(),
+            Transaction::write_vec(vec![0xA8, 0x00]),
+            Transaction::write_vec(frame[..10].to_vec()),
+            Transaction::transaction_end(),
+            Transaction::transaction_start(),
+            Transaction::write_vec(vec![0xA8, 0x0A]),
+            Transaction::write_vec(frame[10..20].to_vec()),
+            Transaction::transaction_end(),
+            Transaction::transaction_start(),
+            Transaction::write_vec(vec![0xA8, 0x14]),
+            Transaction::write_vec(frame[20..].to_vec()),
+            Transaction::transaction_end(),
+            Transaction::transaction_start(),
+            Transaction::write_vec(vec![0x20, 0x00]),
+            Transaction::read_vec(frame[..10].to_vec()),
+            Transaction::transaction_end(),
+            Transaction::transaction_start(),
+            Transaction::write_vec(vec![0x20, 0x0A]),
+            Transaction::read_vec(frame[10..12].to_vec()),
+            Transaction::transaction_end(),
+        ];
+        let mut driver = Driver::new(Mock::new(&expectations));
+        driver.set_max_transfer_len(12);
+
+        driver.write_buffer(BBC0_FBTXS, &frame).unwrap();
+        let mut buf = [0u8; 12];
+        driver.read_buffer(BBC0_FBRXS, &mut buf).unwrap();
+        assert_eq!(buf[..], frame[..12]);
+
+        driver.release().done();
+    }
+
+    #[test]
+    fn test_buffer_address_range() {
+        // The last bytes of the address space, split in two transfers
+        let expectations = [
+            Transaction::transaction_start(),
+            Transaction::write_vec(vec![0xBF, 0xF6]),
+            Transaction::write_vec(vec![0; 8]),
+            Transaction::transaction_end(),
+            Transaction::transaction_start(),
+            Transaction::write_vec(vec![0xBF, 0xFE]),
+            Transaction::write_vec(vec![0; 2]),
+            Transaction::transaction_end(),
+        ];
+        let mut driver = Driver::new(Mock::new(&expectations));
+        driver.set_max_transfer_len(MAX_COMMAND_LEN);
+
+        driver.write_buffer(0x3FF6, &[0; 10]).unwrap();
+        assert_eq!(
+            driver.write_buffer(0x3FF7, &[0; 10]),
+            Err(Error::AddressOutOfRange {
+                addr: 0x3FF7,
+                len: 10
+            })
+        );
+
+        // No wrap around of the address, nor of the end
+        let mut buf = [0u8; 1];
+        assert_eq!(
+            driver.read_buffer(0xFFFF, &mut buf),
+            Err(Error::AddressOutOfRange {
+                addr: 0xFFFF,
+                len: 1
+            })
+        );
+        assert_eq!(
+            driver.read_buffer(0x4000, &mut buf),
+            Err(Error::AddressOutOfRange {
+                addr: 0x4000,
+                len: 1
+            })
+        );
+
+        driver.release().done();
+    }
+
+    #[test]
+    fn test_write_frame() {
+        let expectations = [
+            Transaction::transaction_start(),
+            Transaction::write_vec(vec![0xB8, 0x00]),
+            Transaction::write_vec(vec![0xAA, 0xBB, 0xCC]),
+            Transaction::transaction_end(),
+            Transaction::transaction_start(),
+            Transaction::write_vec(vec![0x84, 0x06]),
+            Transaction::write_vec(vec![0x03, 0x00]),
+            Transaction::transaction_end(),
+        ];
+        let mut driver = Driver::new(Mock::new(&expectations));
+
+        driver.write_frame(Band::Rf24, &[0xAA, 0xBB, 0xCC]).unwrap();
+
+        driver.release().done();
+    }
+
+    #[test]
+    fn test_write_frame_too_long() {
+        let mut driver = Driver::new(Mock::new(&[]));
+
+        let frame = [0u8; MAX_FRAME_LEN + 1];
+        assert_eq!(
+            driver.write_frame(Band::Rf09, &frame),
+            Err(Error::FrameTooLong { len: 2048 })
+        );
+
+        driver.release().done();
+    }
+
+    #[test]
+    fn test_read_frame() {
+        let expectations = [
+            Transaction::transaction_start(),
+            Transaction::write_vec(vec![0x03, 0x04]),
+            Transaction::read_vec(vec![0x02, 0x00]),
+            Transaction::transaction_end(),
+            Transaction::transaction_start(),
+            Transaction::write_vec(vec![0x20, 0x00]),
+            Transaction::read_vec(vec![0x11, 0x22]),
+            Transaction::transaction_end(),
+        ];
+        let mut driver = Driver::new(Mock::new(&expectations));
+
+        let mut buf = [0u8; 16];
+        let len = driver.read_frame(Band::Rf09, &mut buf).unwrap();
+        assert_eq!(&buf[..len], &[0x11, 0x22]);
+
+        driver.release().done();
+    }
+
+    #[test]
+    fn test_read_frame_buffer_too_small() {
+        let expectations = [
+            Transaction::transaction_start(),
+            Transaction::write_vec(vec![0x03, 0x04]),
+            Transaction::read_vec(vec![0xFF, 0x07]),
+            Transaction::transaction_end(),
+        ];
+        let mut driver = Driver::new(Mock::new(&expectations));
+
+        let mut buf = [0u8; 16];
+        assert_eq!(
+            driver.read_frame(Band::Rf09, &mut buf),
+            Err(Error::BufferTooSmall {
+                len: 2047,
+                capacity: 16
+            })
+        );
+
+        driver.release().done();
+    }
+
+    /// Expectations for a single register read returning `value`
+    fn read_reg(addr: u16, value: u8) -> [Transaction<u8>; 3] {
+        let [hi, lo] = generate_read_header(addr);
+        [
+            Transaction::transaction_start(),
+            Transaction::transfer_in_place(vec![hi, lo, 0x00], vec![0x00, 0x00, value]),
+            Transaction::transaction_end(),
+        ]
+    }
+
+    /// Expectations for a single register write
+    fn write_reg(addr: u16, value: u8) -> [Transaction<u8>; 3] {
+        let [hi, lo] = generate_write_header(addr);
+        [
+            Transaction::transaction_start(),
+            Transaction::write_vec(vec![hi, lo, value]),
+            Transaction::transaction_end(),
+        ]
+    }
+
+    #[test]
+    fn test_init() {
+        let expectations = [
+            write_reg(0x0005, 0x07),
+            // RF24 wakes up one poll after RF09
+            read_reg(0x0000, 0x01),
+            read_reg(0x0001, 0x00),
+            read_reg(0x0000, 0x00),
+            read_reg(0x0001, 0x01),
+            read_reg(0x000D, 0x35),
+            read_reg(0x000E, 0x03),
+            write_reg(0x0006, 0x0C),
+            write_reg(0x000B, 0x12),
+            write_reg(0x0100, 0x03),
+            write_reg(0x0200, 0x00),
+            write_reg(0x0300, 0x02),
+            write_reg(0x0400, 0x00),
+        ]
+        .concat();
+        let mut driver = Driver::new(Mock::new(&expectations));
+
+        let config = InitConfig {
+            cfg: RfCfg::reset().with_irqp(true),
+            chip_mode: ChipMode::IqRadioMode,
+            rf09_irqm: RfnIrqm::new().with_wakeup(true).with_trxrdy(true),
+            bbc0_irqm: BbcnIrqm::new().with_rxfe(true),
+            ..Default::default()
+        };
+        let mut radio = Radio::new();
+        radio.rf_clko.value.set_os(ClockOutput::Off);
+        let device = driver.init(&mut radio, &mut NoopDelay, &config).unwrap();
+        assert_eq!(
+            device,
+            DeviceInfo {
+                part: DevicePartNumber::AT86RF215IQ,
+                version: 0x03,
+            }
+        );
+        assert!(!device.has_baseband());
+
+        // The shadow follows the chip through the reset and the writes
+        assert_eq!(radio.rf_clko.value.os(), ClockOutput::Mhz26);
+        assert_eq!(radio.rf_rst.value.cmd(), ChipResetCmd::Nop);
+        assert_eq!(radio.rf_pn.value.pn(), DevicePartNumber::AT86RF215IQ);
+        assert_eq!(radio.rf_vn.value.vn(), 0x03);
+        assert_eq!(radio.rf_cfg.value.into_bits(), 0x0C);
+        assert_eq!(radio.rf_iqifc1.value.chpm(), ChipMode::IqRadioMode);
+        assert_eq!(radio.bbc0.irqm.value.into_bits(), 0x02);
+        assert!(!radio.rf_cfg.is_dirty());
+        assert!(!radio.bbc0.irqm.is_dirty());
+
+        driver.release().done();
+    }
+
+    #[test]
+    fn test_init_wakeup_timeout() {
+        // Polled immediately, then after each of the 2 intervals
+        let mut expectations = write_reg(0x0005, 0x07).to_vec();
+        for _ in 0..3 {
+            expectations.extend(read_reg(0x0000, 0x01));
+            expectations.extend(read_reg(0x0001, 0x00));
+        }
+        let mut driver = Driver::new(Mock::new(&expectations));
+
+        let config = InitConfig {
+            wakeup_timeout_us: 200,
+            poll_interval_us: 100,
+            ..Default::default()
+        };
+        assert_eq!(
+            driver.init(&mut Radio::new(), &mut NoopDelay, &config),
+            Err(Error::WakeupTimeout { band: Band::Rf24 })
+        );
+
+        driver.release().done();
+    }
+
+    #[test]
+    fn test_init_unsupported_device() {
+        let expectations = [
+            write_reg(0x0005, 0x07),
+            read_reg(0x0000, 0x01),
+            read_reg(0x0001, 0x01),
+            read_reg(0x000D, 0x34),
+            read_reg(0x000E, 0x02),
+        ]
+        .concat();
+        let mut driver = Driver::new(Mock::new(&expectations));
+
+        // Nothing is programmed on an unknown device
+        assert_eq!(
+            driver.init(&mut Radio::new(), &mut NoopDelay, &InitConfig::default()),
+            Err(Error::UnsupportedDevice { pn: 0x34, vn: 0x02 })
+        );
+
+        driver.release().done();
+    }
+}
@@ -1,2 +1,20 @@
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(any(feature = "std", test))]
+extern crate std;
+
+pub mod driver;
 pub mod radio;
 pub mod registers;
+
+#[cfg(feature = "async")]
+pub mod async_driver;
+
+#[cfg(feature = "spidev")]
+pub mod spidev;
+
+#[cfg(feature = "sim")]
+pub mod sim;
@@ -1,512 +1,944 @@
+#[cfg(feature = "alloc")]
+use embedded_hal::spi::SpiDevice;
+
+#[cfg(all(feature = "alloc", feature = "async"))]
+use crate::async_driver::AsyncDriver;
+#[cfg(feature = "alloc")]
+use crate::driver::{Driver, Error};
 use crate::registers::*;
+use core::fmt;
+
+/// Start of the RF09 transceiver registers
+pub const RF09_BASE: u16 = 0x0100;
+
+/// Start of the RF24 transceiver registers
+pub const RF24_BASE: u16 = 0x0200;
+
+/// Start of the BBC0 baseband registers
+pub const BBC0_BASE: u16 = 0x0300;
+
+/// Start of the BBC1 baseband registers
+pub const BBC1_BASE: u16 = 0x0400;
+
+/// Declares a register block generic over its base address, its reset
+/// values, its register list and a trait to use it independently of the base.
+macro_rules! register_block {
+    (
+        $(#[$meta:meta])*
+        pub struct $name:ident;
+        pub trait $trait:ident;
+        pub const $count:ident;
+        {
+            $( $field:ident: $wrapper:ident<$ty:ident, $addr:literal, $size:literal> = $reset:expr, )*
+        }
+    ) => {
+        paste::paste! {
+            #[doc = concat!("Number of registers in a [`", stringify!($name), "`]")]
+            pub const $count: usize = [$( stringify!($field) ),*].len();
+
+            $(#[$meta])*
+            #[derive(Debug, Clone)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize))]
+            pub struct $name<const BASE: u16> {
+                $( pub $field: $wrapper<$ty, $addr, $size, BASE>, )*
+            }
+
+            impl<const BASE: u16> $name<BASE> {
+                /// Every register of the block, in declaration order
+                pub const REGISTERS: [RegisterInfo; $count] = [
+                    $(
+                        RegisterInfo {
+                            name: stringify!($field),
+                            block: Block::from_base(BASE),
+                            address: BASE + $addr,
+                            size: $size,
+                            access: Access::$wrapper,
+                        },
+                    )*
+                ];
+
+                /// Register values after power-on or an RF_RST chip reset
+                pub const fn reset_defaults() -> Self {
+                    Self {
+                        $( $field: $wrapper::new($reset), )*
+                    }
+                }
+            }
+
+            impl<const BASE: u16> Default for $name<BASE> {
+                fn default() -> Self {
+                    Self::reset_defaults()
+                }
+            }
+
+            // serde cannot derive `Deserialize` for const generics, missing
+            // registers keep their reset value
+            #[cfg(feature = "serde")]
+            const _: () = {
+                #[derive(serde::Deserialize)]
+                #[serde(deny_unknown_fields)]
+                struct Values {
+                    $(
+                        #[serde(default)]
+                        $field: Option<$ty>,
+                    )*
+                }
+
+                impl<'de, const BASE: u16> serde::Deserialize<'de> for $name<BASE> {
+                    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                        let values = Values::deserialize(deserializer)?;
+                        let mut block = Self::reset_defaults();
+                        $(
+                            if let Some(value) = values.$field {
+                                block.$field = $wrapper::new(value);
+                            }
+                        )*
+                        Ok(block)
+                    }
+                }
+            };
+
+            #[doc = concat!("Access to a [`", stringify!($name), "`] independent of its base address")]
+            ///
+            /// The accessors return the register values, write them to the chip
+            /// with [`Radio::flush`] or through [`registers_mut`](Self::registers_mut).
+            pub trait $trait {
+                /// Band the block belongs to
+                fn band(&self) -> Band;
+
+                /// Every register, in declaration order
+                fn registers(&self) -> [&dyn Register; $count];
+
+                /// Every register, in declaration order
+                fn registers_mut(&mut self) -> [&mut dyn Register; $count];
+
+                $(
+                    fn $field(&self) -> &$ty;
+
+                    fn [<$field _mut>](&mut self) -> &mut $ty;
+                )*
+            }
+
+            impl<const BASE: u16> $trait for $name<BASE> {
+                fn band(&self) -> Band {
+                    Block::from_base(BASE).band().expect("block without band")
+                }
+
+                fn registers(&self) -> [&dyn Register; $count] {
+                    [$( &self.$field, )*]
+                }
+
+                fn registers_mut(&mut self) -> [&mut dyn Register; $count] {
+                    [$( &mut self.$field, )*]
+                }
+
+                $(
+                    fn $field(&self) -> &$ty {
+                        &self.$field.value
+                    }
+
+                    fn [<$field _mut>](&mut self) -> &mut $ty {
+                        &mut self.$field.value
+                    }
+                )*
+            }
+        }
+    };
+}
 
-#[derive(Debug, Clone)]
-pub struct Radio {
-    // =========================================================================
-    // Common Chip Registers (0x0000-0x000E)
-    // =========================================================================
+/// Declares [`Radio`] from the list of chip level registers, its reset values
+/// and the chip part of [`REGISTER_MAP`], so they cannot drift apart.
+macro_rules! radio {
+    ( $( $field:ident: $wrapper:ident<$ty:ident, $addr:literal, $size:literal> = $reset:expr, )* ) => {
+        /// Number of chip level registers in [`Radio`]
+        pub const CHIP_REGISTER_COUNT: usize = [$( stringify!($field) ),*].len();
+
+        const CHIP_REGISTERS: [RegisterInfo; CHIP_REGISTER_COUNT] = [
+            $(
+                RegisterInfo {
+                    name: stringify!($field),
+                    block: Block::Chip,
+                    address: $addr,
+                    size: $size,
+                    access: Access::$wrapper,
+                },
+            )*
+        ];
+
+        /// Shadow copy of every register of the chip
+        ///
+        /// With the `serde` feature a `Radio` can be stored as a configuration
+        /// profile. Registers missing from a profile keep their reset value.
+        /// Loaded registers are clean, apply a profile with
+        /// [`config_writes`](Self::config_writes) rather than [`flush`](Self::flush).
+        #[derive(Debug, Clone)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+        pub struct Radio {
+            $( pub $field: $wrapper<$ty, $addr, $size>, )*
+
+            pub rf09: Transceiver<RF09_BASE>,
+            pub rf24: Transceiver<RF24_BASE>,
+            pub bbc0: Baseband<BBC0_BASE>,
+            pub bbc1: Baseband<BBC1_BASE>,
+        }
 
-    // Read-only chip identification
-    pub rf_pn: ReadOnly<RfPn, 0x000D, 1>,
-    pub rf_vn: ReadOnly<RfVn, 0x000E, 1>,
+        impl Radio {
+            /// Register values after power-on or an RF_RST chip reset
+            ///
+            /// Registers whose reset value is status (IRQS, counters, measurements)
+            /// start out as the chip reports them right after the reset.
+            pub const fn reset_defaults() -> Self {
+                Self {
+                    $( $field: $wrapper::new($reset), )*
+
+                    rf09: Transceiver::reset_defaults(),
+                    rf24: Transceiver::reset_defaults(),
+                    bbc0: Baseband::reset_defaults(),
+                    bbc1: Baseband::reset_defaults(),
+                }
+            }
+
+            /// Every register, in [`REGISTER_MAP`] order
+            pub fn registers(&self) -> impl Iterator<Item = &dyn Register> {
+                [$( &self.$field as &dyn Register, )*]
+                    .into_iter()
+                    .chain(self.rf09.registers())
+                    .chain(self.rf24.registers())
+                    .chain(self.bbc0.registers())
+                    .chain(self.bbc1.registers())
+            }
+
+            /// Every register, in [`REGISTER_MAP`] order
+            pub fn registers_mut(&mut self) -> impl Iterator<Item = &mut dyn Register> {
+                [$( &mut self.$field as &mut dyn Register, )*]
+                    .into_iter()
+                    .chain(self.rf09.registers_mut())
+                    .chain(self.rf24.registers_mut())
+                    .chain(self.bbc0.registers_mut())
+                    .chain(self.bbc1.registers_mut())
+            }
+        }
+    };
+}
 
-    // Chip reset command
-    pub rf_rst: WriteOnly<RfRst, 0x0005, 1>,
+/// Number of registers in [`Radio`]
+pub const REGISTER_COUNT: usize =
+    CHIP_REGISTER_COUNT + 2 * TRANSCEIVER_REGISTER_COUNT + 2 * BASEBAND_REGISTER_COUNT;
+
+/// Every register of [`Radio`]: chip registers, then RF09, RF24, BBC0 and BBC1
+pub const REGISTER_MAP: &[RegisterInfo] = &build_register_map();
+
+const fn build_register_map() -> [RegisterInfo; REGISTER_COUNT] {
+    let blocks: [&[RegisterInfo]; 5] = [
+        &CHIP_REGISTERS,
+        &Transceiver::<RF09_BASE>::REGISTERS,
+        &Transceiver::<RF24_BASE>::REGISTERS,
+        &Baseband::<BBC0_BASE>::REGISTERS,
+        &Baseband::<BBC1_BASE>::REGISTERS,
+    ];
+
+    let mut map = [CHIP_REGISTERS[0]; REGISTER_COUNT];
+    let mut n = 0;
+    let mut b = 0;
+    while b < blocks.len() {
+        let mut i = 0;
+        while i < blocks[b].len() {
+            map[n] = blocks[b][i];
+            n += 1;
+            i += 1;
+        }
+        b += 1;
+    }
+    map
+}
 
-    // Configuration registers
-    pub rf_cfg: ReadWrite<RfCfg, 0x0006, 1>,
-    pub rf_clko: ReadWrite<RfClko, 0x0007, 1>,
-    pub rf_bmdvc: ReadWrite<RfBmdvc, 0x0008, 1>,
-    pub rf_xoc: ReadWrite<RfXoc, 0x0009, 1>,
-    pub rf_iqifc0: ReadWrite<RfIqifc0, 0x000A, 1>,
-    pub rf_iqifc1: ReadWrite<RfIqifc1, 0x000B, 1>,
-    pub rf_iqifc2: ReadOnly<RfIqifc2, 0x000C, 1>,
-
-    // =========================================================================
-    // RF09 Sub-1GHz Radio Registers (0x0100-0x0128)
-    // =========================================================================
-
-    // Status and interrupts
-    pub rf09_irqs: ReadOnly<RfnIrqs, 0x0100, 1>,
-    pub rf09_irqm: ReadWrite<RfnIrqm, 0x0100, 1>,
-    pub rf09_state: ReadOnly<RfnState, 0x0102, 1>,
+/// Address block of the register map
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Block {
+    /// Common chip registers, including the IRQS registers of all blocks
+    Chip,
+    /// Sub-1GHz transceiver
+    Rf09,
+    /// 2.4GHz transceiver
+    Rf24,
+    /// Sub-1GHz baseband
+    Bbc0,
+    /// 2.4GHz baseband
+    Bbc1,
+}
 
-    // Commands and control
-    pub rf09_cmd: WriteOnly<RfnCmd, 0x0103, 1>,
-    pub rf09_auxs: ReadWrite<RfnAuxs, 0x0101, 1>,
+impl Block {
+    /// Block starting at `base`
+    ///
+    /// # Panics
+    /// If `base` is not the start of a transceiver or baseband block.
+    pub const fn from_base(base: u16) -> Self {
+        match base {
+            RF09_BASE => Self::Rf09,
+            RF24_BASE => Self::Rf24,
+            BBC0_BASE => Self::Bbc0,
+            BBC1_BASE => Self::Bbc1,
+            _ => panic!("not a register block base address"),
+        }
+    }
 
-    // Channel configuration
-    pub rf09_cs: ReadWrite<RfnCs, 0x0104, 1>,
-    pub rf09_ccf0: ReadWrite<RfnCcf0, 0x0105, 2>,
-    pub rf09_cn: ReadWrite<RfnCn, 0x0107, 2>,
+    /// Band of a transceiver or baseband block
+    pub const fn band(self) -> Option<Band> {
+        match self {
+            Self::Chip => None,
+            Self::Rf09 | Self::Bbc0 => Some(Band::Rf09),
+            Self::Rf24 | Self::Bbc1 => Some(Band::Rf24),
+        }
+    }
 
-    // Receiver configuration
-    pub rf09_rxbwc: ReadWrite<RfnRxbwc, 0x0109, 1>,
-    pub rf09_rxdfe: ReadWrite<RfnRxdfe, 0x010A, 1>,
-    pub rf09_agcc: ReadWrite<RfnAgcc, 0x010B, 1>,
-    pub rf09_agcs: ReadWrite<RfnAgcs, 0x010C, 1>,
+    /// First and last register address documented for the block
+    pub const fn address_range(self) -> (u16, u16) {
+        match self {
+            Self::Chip => (0x0000, 0x000E),
+            Self::Rf09 => (0x0100, 0x0128),
+            Self::Rf24 => (0x0200, 0x0228),
+            Self::Bbc0 => (0x0300, 0x0394),
+            Self::Bbc1 => (0x0400, 0x0494),
+        }
+    }
+}
 
-    // Energy detection
-    pub rf09_edc: ReadWrite<RfnEdc, 0x010E, 1>,
-    pub rf09_edd: ReadWrite<RfnEdd, 0x010F, 1>,
-    pub rf09_edv: ReadOnly<RfnEdv, 0x0110, 1>,
+impl fmt::Display for Block {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Chip => "Common",
+            Self::Rf09 => "RF09",
+            Self::Rf24 => "RF24",
+            Self::Bbc0 => "BBC0",
+            Self::Bbc1 => "BBC1",
+        })
+    }
+}
 
-    // Random number
-    pub rf09_rndv: ReadOnly<RfnRndv, 0x0111, 1>,
+/// Static description of a [`Radio`] register
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RegisterInfo {
+    /// Field name within [`Radio`] or its block struct
+    pub name: &'static str,
+    pub block: Block,
+    pub address: u16,
+    pub size: usize,
+    pub access: Access,
+}
 
-    // Transmitter configuration
-    pub rf09_txcutc: ReadWrite<RfnTxcutc, 0x0112, 1>,
-    pub rf09_txdfe: ReadWrite<RfnTxdfe, 0x0113, 1>,
-    pub rf09_pac: ReadWrite<RfnPac, 0x0114, 1>,
-    pub rf09_padfe: ReadWrite<RfnPadfe, 0x0116, 1>,
+impl RegisterInfo {
+    /// Address of the last byte of the register
+    pub const fn last_address(&self) -> u16 {
+        self.address + self.size as u16 - 1
+    }
 
-    // PLL configuration
-    pub rf09_pll: ReadWrite<RfnPll, 0x0121, 1>,
-    pub rf09_pllcf: ReadOnly<RfnPllcf, 0x0122, 1>,
+    /// Returns true if the two registers share at least one address.
+    pub const fn overlaps(&self, other: &RegisterInfo) -> bool {
+        self.address <= other.last_address() && other.address <= self.last_address()
+    }
+}
 
-    // TX calibration
-    pub rf09_txci: ReadWrite<RfnTxci, 0x0125, 1>,
-    pub rf09_txcq: ReadWrite<RfnTxcq, 0x0126, 1>,
-    pub rf09_txdaci: ReadWrite<RfnTxdaci, 0x0127, 1>,
-    pub rf09_txdacq: ReadWrite<RfnTxdacq, 0x0128, 1>,
+// =============================================================================
+// Common Chip Registers (0x0000-0x000E)
+// =============================================================================
 
-    // =========================================================================
-    // RF24 2.4GHz Radio Registers (0x0200-0x0228)
-    // =========================================================================
+radio! {
+    // Interrupt status, one register per transceiver and baseband
+    rf09_irqs: ReadOnly<RfnIrqs, 0x0000, 1> = RfnIrqs::reset(),
+    rf24_irqs: ReadOnly<RfnIrqs, 0x0001, 1> = RfnIrqs::reset(),
+    bbc0_irqs: ReadOnly<BbcnIrqs, 0x0002, 1> = BbcnIrqs::reset(),
+    bbc1_irqs: ReadOnly<BbcnIrqs, 0x0003, 1> = BbcnIrqs::reset(),
 
-    // Status and interrupts
-    pub rf24_irqs: ReadOnly<RfnIrqs, 0x0200, 1>,
-    pub rf24_irqm: ReadWrite<RfnIrqm, 0x0200, 1>,
-    pub rf24_state: ReadOnly<RfnState, 0x0202, 1>,
+    // Read-only chip identification
+    rf_pn: ReadOnly<RfPn, 0x000D, 1> = RfPn::reset(),
+    rf_vn: ReadOnly<RfVn, 0x000E, 1> = RfVn::reset(),
+
+    // Chip reset command
+    rf_rst: WriteOnly<RfRst, 0x0005, 1> = RfRst::reset(),
+
+    // Configuration registers
+    rf_cfg: ReadWrite<RfCfg, 0x0006, 1> = RfCfg::reset(),
+    rf_clko: ReadWrite<RfClko, 0x0007, 1> = RfClko::reset(),
+    rf_bmdvc: ReadWrite<RfBmdvc, 0x0008, 1> = RfBmdvc::reset(),
+    rf_xoc: ReadWrite<RfXoc, 0x0009, 1> = RfXoc::reset(),
+    rf_iqifc0: ReadWrite<RfIqifc0, 0x000A, 1> = RfIqifc0::reset(),
+    rf_iqifc1: ReadWrite<RfIqifc1, 0x000B, 1> = RfIqifc1::reset(),
+    rf_iqifc2: ReadOnly<RfIqifc2, 0x000C, 1> = RfIqifc2::reset(),
+}
+
+// =============================================================================
+// Transceiver Registers, RF09 (0x0100-0x0128) and RF24 (0x0200-0x0228)
+// =============================================================================
+
+register_block! {
+    /// Registers of a transceiver, RF09 or RF24 depending on `BASE`
+    pub struct Transceiver;
+    pub trait TransceiverRegs;
+    pub const TRANSCEIVER_REGISTER_COUNT;
+    {
+    // Interrupt mask and state
+    irqm: ReadWrite<RfnIrqm, 0x00, 1> = RfnIrqm::reset(),
+    state: ReadOnly<RfnState, 0x02, 1> = RfnState::reset(),
 
     // Commands and control
-    pub rf24_cmd: WriteOnly<RfnCmd, 0x0203, 1>,
-    pub rf24_auxs: ReadWrite<RfnAuxs, 0x0201, 1>,
+    cmd: WriteOnly<RfnCmd, 0x03, 1> = RfnCmd::reset(),
+    auxs: ReadWrite<RfnAuxs, 0x01, 1> = RfnAuxs::reset(),
 
     // Channel configuration
-    pub rf24_cs: ReadWrite<RfnCs, 0x0204, 1>,
-    pub rf24_ccf0: ReadWrite<RfnCcf0, 0x0205, 2>,
-    pub rf24_cn: ReadWrite<RfnCn, 0x0207, 2>,
+    cs: ReadWrite<RfnCs, 0x04, 1> = RfnCs::reset(),
+    ccf0: ReadWrite<RfnCcf0, 0x05, 2> = if BASE == RF09_BASE {
+        RfnCcf0::reset_rf09()
+    } else {
+        RfnCcf0::reset_rf24()
+    },
+    cnl: ReadWrite<RfnCnl, 0x07, 1> = RfnCnl::reset(),
+    cnm: ReadWrite<RfnCnm, 0x08, 1> = RfnCnm::reset(),
 
     // Receiver configuration
-    pub rf24_rxbwc: ReadWrite<RfnRxbwc, 0x0209, 1>,
-    pub rf24_rxdfe: ReadWrite<RfnRxdfe, 0x020A, 1>,
-    pub rf24_agcc: ReadWrite<RfnAgcc, 0x020B, 1>,
-    pub rf24_agcs: ReadWrite<RfnAgcs, 0x020C, 1>,
+    rxbwc: ReadWrite<RfnRxbwc, 0x09, 1> = RfnRxbwc::reset(),
+    rxdfe: ReadWrite<RfnRxdfe, 0x0A, 1> = RfnRxdfe::reset(),
+    agcc: ReadWrite<RfnAgcc, 0x0B, 1> = RfnAgcc::reset(),
+    agcs: ReadWrite<RfnAgcs, 0x0C, 1> = RfnAgcs::reset(),
+    rssi: ReadOnly<RfnRssi, 0x0D, 1> = RfnRssi::reset(),
 
     // Energy detection
-    pub rf24_edc: ReadWrite<RfnEdc, 0x020E, 1>,
-    pub rf24_edd: ReadWrite<RfnEdd, 0x020F, 1>,
-    pub rf24_edv: ReadOnly<RfnEdv, 0x0210, 1>,
+    edc: ReadWrite<RfnEdc, 0x0E, 1> = RfnEdc::reset(),
+    edd: ReadWrite<RfnEdd, 0x0F, 1> = RfnEdd::reset(),
+    edv: ReadOnly<RfnEdv, 0x10, 1> = RfnEdv::reset(),
 
     // Random number
-    pub rf24_rndv: ReadOnly<RfnRndv, 0x0211, 1>,
+    rndv: ReadOnly<RfnRndv, 0x11, 1> = RfnRndv::reset(),
 
     // Transmitter configuration
-    pub rf24_txcutc: ReadWrite<RfnTxcutc, 0x0212, 1>,
-    pub rf24_txdfe: ReadWrite<RfnTxdfe, 0x0213, 1>,
-    pub rf24_pac: ReadWrite<RfnPac, 0x0214, 1>,
-    pub rf24_padfe: ReadWrite<RfnPadfe, 0x0216, 1>,
+    txcutc: ReadWrite<RfnTxcutc, 0x12, 1> = RfnTxcutc::reset(),
+    txdfe: ReadWrite<RfnTxdfe, 0x13, 1> = RfnTxdfe::reset(),
+    pac: ReadWrite<RfnPac, 0x14, 1> = RfnPac::reset(),
+    padfe: ReadWrite<RfnPadfe, 0x16, 1> = RfnPadfe::reset(),
 
     // PLL configuration
-    pub rf24_pll: ReadWrite<RfnPll, 0x0221, 1>,
-    pub rf24_pllcf: ReadOnly<RfnPllcf, 0x0222, 1>,
+    pll: ReadWrite<RfnPll, 0x21, 1> = RfnPll::reset(),
+    pllcf: ReadOnly<RfnPllcf, 0x22, 1> = RfnPllcf::reset(),
 
     // TX calibration
-    pub rf24_txci: ReadWrite<RfnTxci, 0x0225, 1>,
-    pub rf24_txcq: ReadWrite<RfnTxcq, 0x0226, 1>,
-    pub rf24_txdaci: ReadWrite<RfnTxdaci, 0x0227, 1>,
-    pub rf24_txdacq: ReadWrite<RfnTxdacq, 0x0228, 1>,
+    txci: ReadWrite<RfnTxci, 0x25, 1> = RfnTxci::reset(),
+    txcq: ReadWrite<RfnTxcq, 0x26, 1> = RfnTxcq::reset(),
+    txdaci: ReadWrite<RfnTxdaci, 0x27, 1> = RfnTxdaci::reset(),
+    txdacq: ReadWrite<RfnTxdacq, 0x28, 1> = RfnTxdacq::reset(),
+    }
+}
 
-    // =========================================================================
-    // BBC0 Sub-1GHz Baseband Registers (0x0300-0x0394)
-    // =========================================================================
+// =============================================================================
+// Baseband Registers, BBC0 (0x0300-0x0394) and BBC1 (0x0400-0x0494)
+// =============================================================================
 
-    // Status and interrupts
-    pub bbc0_irqs: ReadOnly<BbcnIrqs, 0x0302, 1>,
-    pub bbc0_irqm: ReadWrite<BbcnIrqm, 0x0300, 1>,
-    pub bbc0_ps: ReadOnly<BbcnPs, 0x0302, 1>,
+register_block! {
+    /// Registers of a baseband core, BBC0 or BBC1 depending on `BASE`
+    pub struct Baseband;
+    pub trait BasebandRegs;
+    pub const BASEBAND_REGISTER_COUNT;
+    {
+    // Interrupt mask and status
+    irqm: ReadWrite<BbcnIrqm, 0x00, 1> = BbcnIrqm::reset(),
+    ps: ReadOnly<BbcnPs, 0x02, 1> = BbcnPs::reset(),
 
     // PHY control
-    pub bbc0_pc: ReadWrite<BbcnPc, 0x0301, 1>,
+    pc: ReadWrite<BbcnPc, 0x01, 1> = BbcnPc::reset(),
 
     // Frame length registers
-    pub bbc0_rxfl: ReadOnly<BbcnRxfl, 0x0304, 2>,
-    pub bbc0_txfl: ReadWrite<BbcnTxfl, 0x0306, 2>,
-    pub bbc0_fbl: ReadOnly<BbcnFbl, 0x0308, 2>,
-    pub bbc0_fbli: ReadWrite<BbcnFbli, 0x030A, 2>,
+    rxfl: ReadOnly<BbcnRxfl, 0x04, 2> = BbcnRxfl::reset(),
+    txfl: ReadWrite<BbcnTxfl, 0x06, 2> = BbcnTxfl::reset(),
+    fbl: ReadOnly<BbcnFbl, 0x08, 2> = BbcnFbl::reset(),
+    fbli: ReadWrite<BbcnFbli, 0x0A, 2> = BbcnFbli::reset(),
 
     // OFDM PHY configuration
-    pub bbc0_ofdmphrtx: ReadWrite<BbcnOfdmphrtx, 0x030C, 1>,
-    pub bbc0_ofdmphrrx: ReadOnly<BbcnOfdmphrrx, 0x030D, 1>,
-    pub bbc0_ofdmc: ReadWrite<BbcnOfdmc, 0x030E, 1>,
-    pub bbc0_ofdmsw: ReadWrite<BbcnOfdmsw, 0x030F, 1>,
+    ofdmphrtx: ReadWrite<BbcnOfdmphrtx, 0x0C, 1> = BbcnOfdmphrtx::reset(),
+    ofdmphrrx: ReadOnly<BbcnOfdmphrrx, 0x0D, 1> = BbcnOfdmphrrx::reset(),
+    ofdmc: ReadWrite<BbcnOfdmc, 0x0E, 1> = BbcnOfdmc::reset(),
+    ofdmsw: ReadWrite<BbcnOfdmsw, 0x0F, 1> = BbcnOfdmsw::reset(),
 
     // O-QPSK PHY configuration
-    pub bbc0_oqpskc0: ReadWrite<BbcnOqpskc0, 0x0310, 1>,
-    pub bbc0_oqpskc1: ReadWrite<BbcnOqpskc1, 0x0311, 1>,
-    pub bbc0_oqpskc2: ReadWrite<BbcnOqpskc2, 0x0312, 1>,
-    pub bbc0_oqpskc3: ReadWrite<BbcnOqpskc3, 0x0313, 1>,
-    pub bbc0_oqpskphrtx: ReadWrite<BbcnOqpskphrtx, 0x0314, 1>,
-    pub bbc0_oqpskphrrx: ReadOnly<BbcnOqpskphrrx, 0x0315, 1>,
+    oqpskc0: ReadWrite<BbcnOqpskc0, 0x10, 1> = BbcnOqpskc0::reset(),
+    oqpskc1: ReadWrite<BbcnOqpskc1, 0x11, 1> = BbcnOqpskc1::reset(),
+    oqpskc2: ReadWrite<BbcnOqpskc2, 0x12, 1> = BbcnOqpskc2::reset(),
+    oqpskc3: ReadWrite<BbcnOqpskc3, 0x13, 1> = BbcnOqpskc3::reset(),
+    oqpskphrtx: ReadWrite<BbcnOqpskphrtx, 0x14, 1> = BbcnOqpskphrtx::reset(),
+    oqpskphrrx: ReadOnly<BbcnOqpskphrrx, 0x15, 1> = BbcnOqpskphrrx::reset(),
 
     // Address filter configuration
-    pub bbc0_afc0: ReadWrite<BbcnAfc0, 0x0320, 1>,
-    pub bbc0_afc1: ReadWrite<BbcnAfc1, 0x0321, 1>,
-    pub bbc0_afftm: ReadWrite<BbcnAfftm, 0x0322, 1>,
-    pub bbc0_affvm: ReadWrite<BbcnAffvm, 0x0323, 1>,
-    pub bbc0_afs: ReadOnly<BbcnAfs, 0x0324, 1>,
+    afc0: ReadWrite<BbcnAfc0, 0x20, 1> = BbcnAfc0::reset(),
+    afc1: ReadWrite<BbcnAfc1, 0x21, 1> = BbcnAfc1::reset(),
+    afftm: ReadWrite<BbcnAfftm, 0x22, 1> = BbcnAfftm::reset(),
+    affvm: ReadWrite<BbcnAffvm, 0x23, 1> = BbcnAffvm::reset(),
+    afs: ReadOnly<BbcnAfs, 0x24, 1> = BbcnAfs::reset(),
 
     // MAC extended address (8 bytes)
-    pub bbc0_macea: ReadWrite<BbcnMacea, 0x0325, 8>,
+    macea: ReadWrite<BbcnMacea, 0x25, 8> = BbcnMacea::reset(),
 
     // MAC PAN ID and short address for filter 0-3
-    pub bbc0_macpidf0: ReadWrite<BbcnMacpid, 0x032D, 2>,
-    pub bbc0_macshaf0: ReadWrite<BbcnMacsha, 0x032F, 2>,
+    macpidf0: ReadWrite<BbcnMacpid, 0x2D, 2> = BbcnMacpid::reset(),
+    macshaf0: ReadWrite<BbcnMacsha, 0x2F, 2> = BbcnMacsha::reset(),
 
-    pub bbc0_macpidf1: ReadWrite<BbcnMacpid, 0x0331, 2>,
-    pub bbc0_macshaf1: ReadWrite<BbcnMacsha, 0x0333, 2>,
+    macpidf1: ReadWrite<BbcnMacpid, 0x31, 2> = BbcnMacpid::reset(),
+    macshaf1: ReadWrite<BbcnMacsha, 0x33, 2> = BbcnMacsha::reset(),
 
-    pub bbc0_macpidf2: ReadWrite<BbcnMacpid, 0x0335, 2>,
-    pub bbc0_macshaf2: ReadWrite<BbcnMacsha, 0x0337, 2>,
+    macpidf2: ReadWrite<BbcnMacpid, 0x35, 2> = BbcnMacpid::reset(),
+    macshaf2: ReadWrite<BbcnMacsha, 0x37, 2> = BbcnMacsha::reset(),
 
-    pub bbc0_macpidf3: ReadWrite<BbcnMacpid, 0x0339, 2>,
-    pub bbc0_macshaf3: ReadWrite<BbcnMacsha, 0x033B, 2>,
+    macpidf3: ReadWrite<BbcnMacpid, 0x39, 2> = BbcnMacpid::reset(),
+    macshaf3: ReadWrite<BbcnMacsha, 0x3B, 2> = BbcnMacsha::reset(),
 
     // Auto mode configuration
-    pub bbc0_amcs: ReadWrite<BbcnAmcs, 0x0340, 1>,
-    pub bbc0_amedt: ReadWrite<BbcnAmedt, 0x0341, 1>,
-    pub bbc0_amaackpd: ReadWrite<BbcnAmaackpd, 0x0342, 1>,
-    pub bbc0_amaackt: ReadWrite<BbcnAmaackt, 0x0343, 2>,
+    amcs: ReadWrite<BbcnAmcs, 0x40, 1> = BbcnAmcs::reset(),
+    amedt: ReadWrite<BbcnAmedt, 0x41, 1> = BbcnAmedt::reset(),
+    amaackpd: ReadWrite<BbcnAmaackpd, 0x42, 1> = BbcnAmaackpd::reset(),
+    amaackt: ReadWrite<BbcnAmaackt, 0x43, 2> = BbcnAmaackt::reset(),
 
     // FSK PHY configuration
-    pub bbc0_fskc0: ReadWrite<BbcnFskc0, 0x0360, 1>,
-    pub bbc0_fskc1: ReadWrite<BbcnFskc1, 0x0361, 1>,
-    pub bbc0_fskc2: ReadWrite<BbcnFskc2, 0x0362, 1>,
-    pub bbc0_fskc3: ReadWrite<BbcnFskc3, 0x0363, 1>,
-    pub bbc0_fskc4: ReadWrite<BbcnFskc4, 0x0364, 1>,
-    pub bbc0_fskpll: ReadWrite<BbcnFskpll, 0x0365, 1>,
-    pub bbc0_fsksfd0: ReadWrite<BbcnFsksfd, 0x0366, 2>,
-    pub bbc0_fsksfd1: ReadWrite<BbcnFsksfd, 0x0368, 2>,
-    pub bbc0_fskphrtx: ReadWrite<BbcnFskphrtx, 0x036A, 1>,
-    pub bbc0_fskphrrx: ReadOnly<BbcnFskphrrx, 0x036B, 1>,
-    pub bbc0_fskrpc: ReadWrite<BbcnFskrpc, 0x036C, 1>,
-    pub bbc0_fskrpcont: ReadWrite<BbcnFskrpcont, 0x036D, 1>,
-    pub bbc0_fskrpcofft: ReadWrite<BbcnFskrpcofft, 0x036E, 1>,
-    pub bbc0_fskrrxfl: ReadOnly<BbcnFskrrxfl, 0x0370, 2>,
-    pub bbc0_fskdm: ReadWrite<BbcnFskdm, 0x0372, 1>,
-    pub bbc0_fskpe0: ReadWrite<BbcnFskpe, 0x0373, 1>,
-    pub bbc0_fskpe1: ReadWrite<BbcnFskpe, 0x0374, 1>,
-    pub bbc0_fskpe2: ReadWrite<BbcnFskpe, 0x0375, 1>,
+    fskc0: ReadWrite<BbcnFskc0, 0x60, 1> = BbcnFskc0::reset(),
+    fskc1: ReadWrite<BbcnFskc1, 0x61, 1> = BbcnFskc1::reset(),
+    fskc2: ReadWrite<BbcnFskc2, 0x62, 1> = BbcnFskc2::reset(),
+    fskc3: ReadWrite<BbcnFskc3, 0x63, 1> = BbcnFskc3::reset(),
+    fskc4: ReadWrite<BbcnFskc4, 0x64, 1> = BbcnFskc4::reset(),
+    fskpll: ReadWrite<BbcnFskpll, 0x65, 1> = BbcnFskpll::reset(),
+    fsksfd0: ReadWrite<BbcnFsksfd, 0x66, 2> = BbcnFsksfd::reset_sfd0(),
+    fsksfd1: ReadWrite<BbcnFsksfd, 0x68, 2> = BbcnFsksfd::reset_sfd1(),
+    fskphrtx: ReadWrite<BbcnFskphrtx, 0x6A, 1> = BbcnFskphrtx::reset(),
+    fskphrrx: ReadOnly<BbcnFskphrrx, 0x6B, 1> = BbcnFskphrrx::reset(),
+    fskrpc: ReadWrite<BbcnFskrpc, 0x6C, 1> = BbcnFskrpc::reset(),
+    fskrpcont: ReadWrite<BbcnFskrpcont, 0x6D, 1> = BbcnFskrpcont::reset(),
+    fskrpcofft: ReadWrite<BbcnFskrpcofft, 0x6E, 1> = BbcnFskrpcofft::reset(),
+    fskrrxfl: ReadOnly<BbcnFskrrxfl, 0x70, 2> = BbcnFskrrxfl::reset(),
+    fskdm: ReadWrite<BbcnFskdm, 0x72, 1> = BbcnFskdm::reset(),
+    fskpe0: ReadWrite<BbcnFskpe, 0x73, 1> = BbcnFskpe::reset_pe0(),
+    fskpe1: ReadWrite<BbcnFskpe, 0x74, 1> = BbcnFskpe::reset_pe1(),
+    fskpe2: ReadWrite<BbcnFskpe, 0x75, 1> = BbcnFskpe::reset_pe2(),
 
     // Phase measurement unit
-    pub bbc0_pmuc: ReadWrite<BbcnPmuc, 0x0380, 1>,
-    pub bbc0_pmuval: ReadOnly<BbcnPmuval, 0x0381, 1>,
-    pub bbc0_pmuqf: ReadOnly<BbcnPmuqf, 0x0382, 1>,
-    pub bbc0_pmui: ReadOnly<BbcnPmui, 0x0383, 1>,
-    pub bbc0_pmuq: ReadOnly<BbcnPmuq, 0x0384, 1>,
+    pmuc: ReadWrite<BbcnPmuc, 0x80, 1> = BbcnPmuc::reset(),
+    pmuval: ReadOnly<BbcnPmuval, 0x81, 1> = BbcnPmuval::reset(),
+    pmuqf: ReadOnly<BbcnPmuqf, 0x82, 1> = BbcnPmuqf::reset(),
+    pmui: ReadOnly<BbcnPmui, 0x83, 1> = BbcnPmui::reset(),
+    pmuq: ReadOnly<BbcnPmuq, 0x84, 1> = BbcnPmuq::reset(),
 
     // Timestamp counter
-    pub bbc0_cntc: ReadWrite<BbcnCntc, 0x0390, 1>,
-    pub bbc0_cnt: ReadOnly<BbcnCnt, 0x0391, 4>,
+    cntc: ReadWrite<BbcnCntc, 0x90, 1> = BbcnCntc::reset(),
+    cnt: ReadOnly<BbcnCnt, 0x91, 4> = BbcnCnt::reset(),
+    }
+}
 
-    // =========================================================================
-    // BBC1 2.4GHz Baseband Registers (0x0400-0x0494)
-    // =========================================================================
+impl Default for Radio {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
-    // Status and interrupts
-    pub bbc1_irqs: ReadOnly<BbcnIrqs, 0x0402, 1>,
-    pub bbc1_irqm: ReadWrite<BbcnIrqm, 0x0400, 1>,
-    pub bbc1_ps: ReadOnly<BbcnPs, 0x0402, 1>,
+impl Radio {
+    /// Create a new Radio with all registers at their reset values
+    ///
+    /// Same as [`reset_defaults`](Self::reset_defaults).
+    pub const fn new() -> Self {
+        Self::reset_defaults()
+    }
 
-    // PHY control
-    pub bbc1_pc: ReadWrite<BbcnPc, 0x0401, 1>,
+    /// Transceiver registers of `band`
+    pub fn transceiver(&self, band: Band) -> &dyn TransceiverRegs {
+        match band {
+            Band::Rf09 => &self.rf09,
+            Band::Rf24 => &self.rf24,
+        }
+    }
 
-    // Frame length registers
-    pub bbc1_rxfl: ReadOnly<BbcnRxfl, 0x0404, 2>,
-    pub bbc1_txfl: ReadWrite<BbcnTxfl, 0x0406, 2>,
-    pub bbc1_fbl: ReadOnly<BbcnFbl, 0x0408, 2>,
-    pub bbc1_fbli: ReadWrite<BbcnFbli, 0x040A, 2>,
+    /// Transceiver registers of `band`
+    pub fn transceiver_mut(&mut self, band: Band) -> &mut dyn TransceiverRegs {
+        match band {
+            Band::Rf09 => &mut self.rf09,
+            Band::Rf24 => &mut self.rf24,
+        }
+    }
 
-    // OFDM PHY configuration
-    pub bbc1_ofdmphrtx: ReadWrite<BbcnOfdmphrtx, 0x040C, 1>,
-    pub bbc1_ofdmphrrx: ReadOnly<BbcnOfdmphrrx, 0x040D, 1>,
-    pub bbc1_ofdmc: ReadWrite<BbcnOfdmc, 0x040E, 1>,
-    pub bbc1_ofdmsw: ReadWrite<BbcnOfdmsw, 0x040F, 1>,
+    /// Baseband registers of `band`
+    pub fn baseband(&self, band: Band) -> &dyn BasebandRegs {
+        match band {
+            Band::Rf09 => &self.bbc0,
+            Band::Rf24 => &self.bbc1,
+        }
+    }
 
-    // O-QPSK PHY configuration
-    pub bbc1_oqpskc0: ReadWrite<BbcnOqpskc0, 0x0410, 1>,
-    pub bbc1_oqpskc1: ReadWrite<BbcnOqpskc1, 0x0411, 1>,
-    pub bbc1_oqpskc2: ReadWrite<BbcnOqpskc2, 0x0412, 1>,
-    pub bbc1_oqpskc3: ReadWrite<BbcnOqpskc3, 0x0413, 1>,
-    pub bbc1_oqpskphrtx: ReadWrite<BbcnOqpskphrtx, 0x0414, 1>,
-    pub bbc1_oqpskphrrx: ReadOnly<BbcnOqpskphrrx, 0x0415, 1>,
+    /// Baseband registers of `band`
+    pub fn baseband_mut(&mut self, band: Band) -> &mut dyn BasebandRegs {
+        match band {
+            Band::Rf09 => &mut self.bbc0,
+            Band::Rf24 => &mut self.bbc1,
+        }
+    }
 
-    // Address filter configuration
-    pub bbc1_afc0: ReadWrite<BbcnAfc0, 0x0420, 1>,
-    pub bbc1_afc1: ReadWrite<BbcnAfc1, 0x0421, 1>,
-    pub bbc1_afftm: ReadWrite<BbcnAfftm, 0x0422, 1>,
-    pub bbc1_affvm: ReadWrite<BbcnAffvm, 0x0423, 1>,
-    pub bbc1_afs: ReadOnly<BbcnAfs, 0x0424, 1>,
+    /// Renders the whole shadow register file, grouped by block.
+    ///
+    /// Each register is printed with its address, raw value and decoded fields:
+    ///
+    /// ```text
+    /// RF09
+    ///   0x0109 rxbwc = 0x09  RXBWC.BW = 9 (1250kHz @ 2000kHz IF), RXBWC.IFS = 0, RXBWC.IFI = 0
+    /// ```
+    pub fn dump(&self) -> RegisterDump<'_> {
+        RegisterDump { radio: self }
+    }
 
-    // MAC extended address (8 bytes)
-    pub bbc1_macea: ReadWrite<BbcnMacea, 0x0425, 8>,
+    /// Collects every modified `ReadWrite` register.
+    #[cfg(feature = "alloc")]
+    pub fn dirty_writes(&mut self) -> BulkWrites<'_> {
+        let mut writes = BulkWrites::new();
+        for reg in self.registers_mut() {
+            if let Some(reg) = reg.as_writable_mut()
+                && reg.is_dirty()
+            {
+                writes
+                    .try_add_dyn(reg)
+                    .expect("Radio registers do not overlap");
+            }
+        }
+        writes
+    }
 
-    // MAC PAN ID and short address for filter 0-3
-    pub bbc1_macpidf0: ReadWrite<BbcnMacpid, 0x042D, 2>,
-    pub bbc1_macshaf0: ReadWrite<BbcnMacsha, 0x042F, 2>,
+    /// Collects every `ReadWrite` register, e.g. to apply a configuration profile.
+    #[cfg(feature = "alloc")]
+    pub fn config_writes(&mut self) -> BulkWrites<'_> {
+        let mut writes = BulkWrites::new();
+        for reg in self.registers_mut() {
+            if reg.access() == Access::ReadWrite
+                && let Some(reg) = reg.as_writable_mut()
+            {
+                writes
+                    .try_add_dyn(reg)
+                    .expect("Radio registers do not overlap");
+            }
+        }
+        writes
+    }
 
-    pub bbc1_macpidf1: ReadWrite<BbcnMacpid, 0x0431, 2>,
-    pub bbc1_macshaf1: ReadWrite<BbcnMacsha, 0x0433, 2>,
+    /// Collects every `ReadWrite` register, the ones cached by the shadow copy.
+    #[cfg(feature = "alloc")]
+    pub fn config_reads(&mut self) -> BulkReads<'_> {
+        let mut reads = BulkReads::new();
+        for reg in self.registers_mut() {
+            if reg.access() == Access::ReadWrite
+                && let Some(reg) = reg.as_readable_mut()
+            {
+                reads.try_add_dyn(reg).expect("BulkReads capacity exceeded");
+            }
+        }
+        reads
+    }
 
-    pub bbc1_macpidf2: ReadWrite<BbcnMacpid, 0x0435, 2>,
-    pub bbc1_macshaf2: ReadWrite<BbcnMacsha, 0x0437, 2>,
+    /// Collects every modified `ReadWrite` register like
+    /// [`dirty_writes`](Self::dirty_writes), bridging the gaps between them
+    /// with clean registers when `cost` finds one longer command cheaper.
+    ///
+    /// A gap is only filled with clean `ReadWrite` configuration registers,
+    /// which rewrite their shadow value. Gaps over read-only, write-only,
+    /// unmapped or side-effecting registers are left as they are.
+    #[cfg(feature = "alloc")]
+    pub fn bridged_writes(&mut self, cost: &WriteCost) -> BulkWrites<'_> {
+        let include = self.bridged_registers(cost);
+        let mut writes = BulkWrites::new();
+        for (reg, include) in self.registers_mut().zip(include) {
+            if include && let Some(reg) = reg.as_writable_mut() {
+                writes
+                    .try_add_dyn(reg)
+                    .expect("Radio registers do not overlap");
+            }
+        }
+        writes
+    }
 
-    pub bbc1_macpidf3: ReadWrite<BbcnMacpid, 0x0439, 2>,
-    pub bbc1_macshaf3: ReadWrite<BbcnMacsha, 0x043B, 2>,
+    /// Flags the modified registers and the gap fillers of
+    /// [`bridged_writes`](Self::bridged_writes), in [`REGISTER_MAP`] order.
+    #[cfg(feature = "alloc")]
+    fn bridged_registers(&self, cost: &WriteCost) -> [bool; REGISTER_COUNT] {
+        let mut regs = self.registers();
+        let regs: [&dyn Register; REGISTER_COUNT] = core::array::from_fn(|_| regs.next().unwrap());
+        let writable = |i: usize| {
+            regs[i]
+                .as_writable()
+                .filter(|_| regs[i].access() == Access::ReadWrite)
+        };
+
+        let mut order: [usize; REGISTER_COUNT] = core::array::from_fn(|i| i);
+        order.sort_unstable_by_key(|&i| REGISTER_MAP[i].address);
+
+        let mut include: [bool; REGISTER_COUNT] =
+            core::array::from_fn(|i| writable(i).is_some_and(|reg| reg.is_dirty()));
+
+        let mut prev: Option<usize> = None;
+        for pos in 0..REGISTER_COUNT {
+            if !include[order[pos]] {
+                continue;
+            }
+            if let Some(start) = prev
+                && Self::can_bridge(&order[start..=pos], &writable, cost)
+            {
+                for &i in &order[start + 1..pos] {
+                    include[i] = true;
+                }
+            }
+            prev = Some(pos);
+        }
+        include
+    }
 
-    // Auto mode configuration
-    pub bbc1_amcs: ReadWrite<BbcnAmcs, 0x0440, 1>,
-    pub bbc1_amedt: ReadWrite<BbcnAmedt, 0x0441, 1>,
-    pub bbc1_amaackpd: ReadWrite<BbcnAmaackpd, 0x0442, 1>,
-    pub bbc1_amaackt: ReadWrite<BbcnAmaackt, 0x0443, 2>,
+    /// Returns true if the clean registers between the first and last of
+    /// `run` fill the gap between them exactly, and can share their command.
+    #[cfg(feature = "alloc")]
+    fn can_bridge<'a>(
+        run: &[usize],
+        writable: &impl Fn(usize) -> Option<&'a dyn Writable>,
+        cost: &WriteCost,
+    ) -> bool {
+        let (first, last) = (run[0], run[run.len() - 1]);
+        let gap = REGISTER_MAP[last].address - REGISTER_MAP[first].last_address() - 1;
+        if gap == 0 || !cost.bridges(gap as usize) {
+            return false;
+        }
 
-    // FSK PHY configuration
-    pub bbc1_fskc0: ReadWrite<BbcnFskc0, 0x0460, 1>,
-    pub bbc1_fskc1: ReadWrite<BbcnFskc1, 0x0461, 1>,
-    pub bbc1_fskc2: ReadWrite<BbcnFskc2, 0x0462, 1>,
-    pub bbc1_fskc3: ReadWrite<BbcnFskc3, 0x0463, 1>,
-    pub bbc1_fskc4: ReadWrite<BbcnFskc4, 0x0464, 1>,
-    pub bbc1_fskpll: ReadWrite<BbcnFskpll, 0x0465, 1>,
-    pub bbc1_fsksfd0: ReadWrite<BbcnFsksfd, 0x0466, 2>,
-    pub bbc1_fsksfd1: ReadWrite<BbcnFsksfd, 0x0468, 2>,
-    pub bbc1_fskphrtx: ReadWrite<BbcnFskphrtx, 0x046A, 1>,
-    pub bbc1_fskphrrx: ReadOnly<BbcnFskphrrx, 0x046B, 1>,
-    pub bbc1_fskrpc: ReadWrite<BbcnFskrpc, 0x046C, 1>,
-    pub bbc1_fskrpcont: ReadWrite<BbcnFskrpcont, 0x046D, 1>,
-    pub bbc1_fskrpcofft: ReadWrite<BbcnFskrpcofft, 0x046E, 1>,
-    pub bbc1_fskrrxfl: ReadOnly<BbcnFskrrxfl, 0x0470, 2>,
-    pub bbc1_fskdm: ReadWrite<BbcnFskdm, 0x0472, 1>,
-    pub bbc1_fskpe0: ReadWrite<BbcnFskpe, 0x0473, 1>,
-    pub bbc1_fskpe1: ReadWrite<BbcnFskpe, 0x0474, 1>,
-    pub bbc1_fskpe2: ReadWrite<BbcnFskpe, 0x0475, 1>,
+        let (Some(head), Some(tail)) = (writable(first), writable(last)) else {
+            return false;
+        };
+        if head.write_stage() != WriteStage::Config || head.triggers() || tail.triggers() {
+            return false;
+        }
 
-    // Phase measurement unit
-    pub bbc1_pmuc: ReadWrite<BbcnPmuc, 0x0480, 1>,
-    pub bbc1_pmuval: ReadOnly<BbcnPmuval, 0x0481, 1>,
-    pub bbc1_pmuqf: ReadOnly<BbcnPmuqf, 0x0482, 1>,
-    pub bbc1_pmui: ReadOnly<BbcnPmui, 0x0483, 1>,
-    pub bbc1_pmuq: ReadOnly<BbcnPmuq, 0x0484, 1>,
+        let mut next = REGISTER_MAP[first].last_address() + 1;
+        for &i in &run[1..run.len() - 1] {
+            let Some(reg) = writable(i) else {
+                return false;
+            };
+            if REGISTER_MAP[i].address != next
+                || reg.write_stage() != WriteStage::Config
+                || reg.triggers()
+            {
+                return false;
+            }
+            next = REGISTER_MAP[i].last_address() + 1;
+        }
+        next == REGISTER_MAP[last].address
+    }
 
-    // Timestamp counter
-    pub bbc1_cntc: ReadWrite<BbcnCntc, 0x0490, 1>,
-    pub bbc1_cnt: ReadOnly<BbcnCnt, 0x0491, 4>,
+    /// Writes only the modified registers, coalescing contiguous ones.
+    ///
+    /// The registers are marked clean once every write succeeded.
+    #[cfg(feature = "alloc")]
+    pub fn flush<SPI: SpiDevice>(
+        &mut self,
+        driver: &mut Driver<SPI>,
+    ) -> Result<(), Error<SPI::Error>> {
+        let mut writes = self.dirty_writes();
+        driver.write_bulk(&writes)?;
+        writes.mark_clean();
+        Ok(())
+    }
+
+    /// Refreshes every `ReadWrite` register from the chip, leaving them clean.
+    ///
+    /// Read-only status registers are not touched, reading IRQS would clear them.
+    #[cfg(feature = "alloc")]
+    pub fn sync<SPI: SpiDevice>(
+        &mut self,
+        driver: &mut Driver<SPI>,
+    ) -> Result<(), Error<SPI::Error>> {
+        driver.read_bulk(&mut self.config_reads())
+    }
+
+    /// Async version of [`flush`](Self::flush).
+    #[cfg(all(feature = "alloc", feature = "async"))]
+    pub async fn flush_async<SPI, IRQ>(
+        &mut self,
+        driver: &mut AsyncDriver<SPI, IRQ>,
+    ) -> Result<(), Error<SPI::Error, IRQ::Error>>
+    where
+        SPI: embedded_hal_async::spi::SpiDevice,
+        IRQ: embedded_hal_async::digital::Wait,
+    {
+        let mut writes = self.dirty_writes();
+        driver.write_bulk(&writes).await?;
+        writes.mark_clean();
+        Ok(())
+    }
+
+    /// Async version of [`sync`](Self::sync).
+    #[cfg(all(feature = "alloc", feature = "async"))]
+    pub async fn sync_async<SPI, IRQ>(
+        &mut self,
+        driver: &mut AsyncDriver<SPI, IRQ>,
+    ) -> Result<(), Error<SPI::Error, IRQ::Error>>
+    where
+        SPI: embedded_hal_async::spi::SpiDevice,
+        IRQ: embedded_hal_async::digital::Wait,
+    {
+        driver.read_bulk(&mut self.config_reads()).await
+    }
 }
 
-impl Default for Radio {
+/// Printable view of every [`Radio`] register, see [`Radio::dump`]
+pub struct RegisterDump<'a> {
+    radio: &'a Radio,
+}
+
+impl fmt::Display for RegisterDump<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut block = None;
+        for (info, reg) in REGISTER_MAP.iter().zip(self.radio.registers()) {
+            if block != Some(info.block) {
+                block = Some(info.block);
+                writeln!(f, "{}", info.block)?;
+            }
+            let value = reg.value();
+            writeln!(
+                f,
+                "  {:#06X} {} = {:#0width$X}  {}",
+                info.address,
+                info.name,
+                value.bits(),
+                value,
+                width = 2 + 2 * info.size,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+// =============================================================================
+// Write Planning
+// =============================================================================
+
+/// Cost model of [`Radio::bridged_writes`], in SPI byte times
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WriteCost {
+    /// Cost of a separate command: its header plus the chip select overhead
+    pub command: usize,
+    /// Cost of each byte written to fill a gap
+    pub byte: usize,
+}
+
+impl WriteCost {
+    pub const fn new(command: usize, byte: usize) -> Self {
+        Self { command, byte }
+    }
+
+    /// Returns true if writing `bytes` filler bytes costs no more than a
+    /// separate command.
+    pub const fn bridges(&self, bytes: usize) -> bool {
+        bytes * self.byte <= self.command
+    }
+}
+
+impl Default for WriteCost {
+    /// The header plus two byte times of chip select turnaround
     fn default() -> Self {
-        Self::new()
+        Self::new(HEADER_LEN + 2, 1)
     }
 }
 
+// =============================================================================
+// Snapshot Diff
+// =============================================================================
+
 impl Radio {
-    /// Create a new Radio with all registers initialized to default values
-    pub fn new() -> Self {
-        Self {
-            // Common chip registers
-            rf_pn: ReadOnly::new(RfPn::new()),
-            rf_vn: ReadOnly::new(RfVn::new()),
-            rf_rst: WriteOnly::new(RfRst::new()),
-            rf_cfg: ReadWrite::new(RfCfg::new()),
-            rf_clko: ReadWrite::new(RfClko::new()),
-            rf_bmdvc: ReadWrite::new(RfBmdvc::new()),
-            rf_xoc: ReadWrite::new(RfXoc::new()),
-            rf_iqifc0: ReadWrite::new(RfIqifc0::new()),
-            rf_iqifc1: ReadWrite::new(RfIqifc1::new()),
-            rf_iqifc2: ReadOnly::new(RfIqifc2::new()),
-
-            // RF09 registers
-            rf09_irqs: ReadOnly::new(RfnIrqs::new()),
-            rf09_irqm: ReadWrite::new(RfnIrqm::new()),
-            rf09_state: ReadOnly::new(RfnState::new()),
-            rf09_cmd: WriteOnly::new(RfnCmd::new()),
-            rf09_auxs: ReadWrite::new(RfnAuxs::new()),
-            rf09_cs: ReadWrite::new(RfnCs::new()),
-            rf09_ccf0: ReadWrite::new(RfnCcf0::new()),
-            rf09_cn: ReadWrite::new(RfnCn::new()),
-            rf09_rxbwc: ReadWrite::new(RfnRxbwc::new()),
-            rf09_rxdfe: ReadWrite::new(RfnRxdfe::new()),
-            rf09_agcc: ReadWrite::new(RfnAgcc::new()),
-            rf09_agcs: ReadWrite::new(RfnAgcs::new()),
-            rf09_edc: ReadWrite::new(RfnEdc::new()),
-            rf09_edd: ReadWrite::new(RfnEdd::new()),
-            rf09_edv: ReadOnly::new(RfnEdv::new()),
-            rf09_rndv: ReadOnly::new(RfnRndv::new()),
-            rf09_txcutc: ReadWrite::new(RfnTxcutc::new()),
-            rf09_txdfe: ReadWrite::new(RfnTxdfe::new()),
-            rf09_pac: ReadWrite::new(RfnPac::new()),
-            rf09_padfe: ReadWrite::new(RfnPadfe::new()),
-            rf09_pll: ReadWrite::new(RfnPll::new()),
-            rf09_pllcf: ReadOnly::new(RfnPllcf::new()),
-            rf09_txci: ReadWrite::new(RfnTxci::new()),
-            rf09_txcq: ReadWrite::new(RfnTxcq::new()),
-            rf09_txdaci: ReadWrite::new(RfnTxdaci::new()),
-            rf09_txdacq: ReadWrite::new(RfnTxdacq::new()),
-
-            // RF24 registers
-            rf24_irqs: ReadOnly::new(RfnIrqs::new()),
-            rf24_irqm: ReadWrite::new(RfnIrqm::new()),
-            rf24_state: ReadOnly::new(RfnState::new()),
-            rf24_cmd: WriteOnly::new(RfnCmd::new()),
-            rf24_auxs: ReadWrite::new(RfnAuxs::new()),
-            rf24_cs: ReadWrite::new(RfnCs::new()),
-            rf24_ccf0: ReadWrite::new(RfnCcf0::new()),
-            rf24_cn: ReadWrite::new(RfnCn::new()),
-            rf24_rxbwc: ReadWrite::new(RfnRxbwc::new()),
-            rf24_rxdfe: ReadWrite::new(RfnRxdfe::new()),
-            rf24_agcc: ReadWrite::new(RfnAgcc::new()),
-            rf24_agcs: ReadWrite::new(RfnAgcs::new()),
-            rf24_edc: ReadWrite::new(RfnEdc::new()),
-            rf24_edd: ReadWrite::new(RfnEdd::new()),
-            rf24_edv: ReadOnly::new(RfnEdv::new()),
-            rf24_rndv: ReadOnly::new(RfnRndv::new()),
-            rf24_txcutc: ReadWrite::new(RfnTxcutc::new()),
-            rf24_txdfe: ReadWrite::new(RfnTxdfe::new()),
-            rf24_pac: ReadWrite::new(RfnPac::new()),
-            rf24_padfe: ReadWrite::new(RfnPadfe::new()),
-            rf24_pll: ReadWrite::new(RfnPll::new()),
-            rf24_pllcf: ReadOnly::new(RfnPllcf::new()),
-            rf24_txci: ReadWrite::new(RfnTxci::new()),
-            rf24_txcq: ReadWrite::new(RfnTxcq::new()),
-            rf24_txdaci: ReadWrite::new(RfnTxdaci::new()),
-            rf24_txdacq: ReadWrite::new(RfnTxdacq::new()),
-
-            // BBC0 registers
-            bbc0_irqs: ReadOnly::new(BbcnIrqs::new()),
-            bbc0_irqm: ReadWrite::new(BbcnIrqm::new()),
-            bbc0_ps: ReadOnly::new(BbcnPs::new()),
-            bbc0_pc: ReadWrite::new(BbcnPc::new()),
-            bbc0_rxfl: ReadOnly::new(BbcnRxfl::new()),
-            bbc0_txfl: ReadWrite::new(BbcnTxfl::new()),
-            bbc0_fbl: ReadOnly::new(BbcnFbl::new()),
-            bbc0_fbli: ReadWrite::new(BbcnFbli::new()),
-            bbc0_ofdmphrtx: ReadWrite::new(BbcnOfdmphrtx::new()),
-            bbc0_ofdmphrrx: ReadOnly::new(BbcnOfdmphrrx::new()),
-            bbc0_ofdmc: ReadWrite::new(BbcnOfdmc::new()),
-            bbc0_ofdmsw: ReadWrite::new(BbcnOfdmsw::new()),
-            bbc0_oqpskc0: ReadWrite::new(BbcnOqpskc0::new()),
-            bbc0_oqpskc1: ReadWrite::new(BbcnOqpskc1::new()),
-            bbc0_oqpskc2: ReadWrite::new(BbcnOqpskc2::new()),
-            bbc0_oqpskc3: ReadWrite::new(BbcnOqpskc3::new()),
-            bbc0_oqpskphrtx: ReadWrite::new(BbcnOqpskphrtx::new()),
-            bbc0_oqpskphrrx: ReadOnly::new(BbcnOqpskphrrx::new()),
-            bbc0_afc0: ReadWrite::new(BbcnAfc0::new()),
-            bbc0_afc1: ReadWrite::new(BbcnAfc1::new()),
-            bbc0_afftm: ReadWrite::new(BbcnAfftm::new()),
-            bbc0_affvm: ReadWrite::new(BbcnAffvm::new()),
-            bbc0_afs: ReadOnly::new(BbcnAfs::new()),
-            bbc0_macea: ReadWrite::new(BbcnMacea::new()),
-            bbc0_macpidf0: ReadWrite::new(BbcnMacpid::new()),
-            bbc0_macshaf0: ReadWrite::new(BbcnMacsha::new()),
-            bbc0_macpidf1: ReadWrite::new(BbcnMacpid::new()),
-            bbc0_macshaf1: ReadWrite::new(BbcnMacsha::new()),
-            bbc0_macpidf2: ReadWrite::new(BbcnMacpid::new()),
-            bbc0_macshaf2: ReadWrite::new(BbcnMacsha::new()),
-            bbc0_macpidf3: ReadWrite::new(BbcnMacpid::new()),
-            bbc0_macshaf3: ReadWrite::new(BbcnMacsha::new()),
-            bbc0_amcs: ReadWrite::new(BbcnAmcs::new()),
-            bbc0_amedt: ReadWrite::new(BbcnAmedt::new()),
-            bbc0_amaackpd: ReadWrite::new(BbcnAmaackpd::new()),
-            bbc0_amaackt: ReadWrite::new(BbcnAmaackt::new()),
-            bbc0_fskc0: ReadWrite::new(BbcnFskc0::new()),
-            bbc0_fskc1: ReadWrite::new(BbcnFskc1::new()),
-            bbc0_fskc2: ReadWrite::new(BbcnFskc2::new()),
-            bbc0_fskc3: ReadWrite::new(BbcnFskc3::new()),
-            bbc0_fskc4: ReadWrite::new(BbcnFskc4::new()),
-            bbc0_fskpll: ReadWrite::new(BbcnFskpll::new()),
-            bbc0_fsksfd0: ReadWrite::new(BbcnFsksfd::new()),
-            bbc0_fsksfd1: ReadWrite::new(BbcnFsksfd::new()),
-            bbc0_fskphrtx: ReadWrite::new(BbcnFskphrtx::new()),
-            bbc0_fskphrrx: ReadOnly::new(BbcnFskphrrx::new()),
-            bbc0_fskrpc: ReadWrite::new(BbcnFskrpc::new()),
-            bbc0_fskrpcont: ReadWrite::new(BbcnFskrpcont::new()),
-            bbc0_fskrpcofft: ReadWrite::new(BbcnFskrpcofft::new()),
-            bbc0_fskrrxfl: ReadOnly::new(BbcnFskrrxfl::new()),
-            bbc0_fskdm: ReadWrite::new(BbcnFskdm::new()),
-            bbc0_fskpe0: ReadWrite::new(BbcnFskpe::new()),
-            bbc0_fskpe1: ReadWrite::new(BbcnFskpe::new()),
-            bbc0_fskpe2: ReadWrite::new(BbcnFskpe::new()),
-            bbc0_pmuc: ReadWrite::new(BbcnPmuc::new()),
-            bbc0_pmuval: ReadOnly::new(BbcnPmuval::new()),
-            bbc0_pmuqf: ReadOnly::new(BbcnPmuqf::new()),
-            bbc0_pmui: ReadOnly::new(BbcnPmui::new()),
-            bbc0_pmuq: ReadOnly::new(BbcnPmuq::new()),
-            bbc0_cntc: ReadWrite::new(BbcnCntc::new()),
-            bbc0_cnt: ReadOnly::new(BbcnCnt::new()),
-
-            // BBC1 registers
-            bbc1_irqs: ReadOnly::new(BbcnIrqs::new()),
-            bbc1_irqm: ReadWrite::new(BbcnIrqm::new()),
-            bbc1_ps: ReadOnly::new(BbcnPs::new()),
-            bbc1_pc: ReadWrite::new(BbcnPc::new()),
-            bbc1_rxfl: ReadOnly::new(BbcnRxfl::new()),
-            bbc1_txfl: ReadWrite::new(BbcnTxfl::new()),
-            bbc1_fbl: ReadOnly::new(BbcnFbl::new()),
-            bbc1_fbli: ReadWrite::new(BbcnFbli::new()),
-            bbc1_ofdmphrtx: ReadWrite::new(BbcnOfdmphrtx::new()),
-            bbc1_ofdmphrrx: ReadOnly::new(BbcnOfdmphrrx::new()),
-            bbc1_ofdmc: ReadWrite::new(BbcnOfdmc::new()),
-            bbc1_ofdmsw: ReadWrite::new(BbcnOfdmsw::new()),
-            bbc1_oqpskc0: ReadWrite::new(BbcnOqpskc0::new()),
-            bbc1_oqpskc1: ReadWrite::new(BbcnOqpskc1::new()),
-            bbc1_oqpskc2: ReadWrite::new(BbcnOqpskc2::new()),
-            bbc1_oqpskc3: ReadWrite::new(BbcnOqpskc3::new()),
-            bbc1_oqpskphrtx: ReadWrite::new(BbcnOqpskphrtx::new()),
-            bbc1_oqpskphrrx: ReadOnly::new(BbcnOqpskphrrx::new()),
-            bbc1_afc0: ReadWrite::new(BbcnAfc0::new()),
-            bbc1_afc1: ReadWrite::new(BbcnAfc1::new()),
-            bbc1_afftm: ReadWrite::new(BbcnAfftm::new()),
-            bbc1_affvm: ReadWrite::new(BbcnAffvm::new()),
-            bbc1_afs: ReadOnly::new(BbcnAfs::new()),
-            bbc1_macea: ReadWrite::new(BbcnMacea::new()),
-            bbc1_macpidf0: ReadWrite::new(BbcnMacpid::new()),
-            bbc1_macshaf0: ReadWrite::new(BbcnMacsha::new()),
-            bbc1_macpidf1: ReadWrite::new(BbcnMacpid::new()),
-            bbc1_macshaf1: ReadWrite::new(BbcnMacsha::new()),
-            bbc1_macpidf2: ReadWrite::new(BbcnMacpid::new()),
-            bbc1_macshaf2: ReadWrite::new(BbcnMacsha::new()),
-            bbc1_macpidf3: ReadWrite::new(BbcnMacpid::new()),
-            bbc1_macshaf3: ReadWrite::new(BbcnMacsha::new()),
-            bbc1_amcs: ReadWrite::new(BbcnAmcs::new()),
-            bbc1_amedt: ReadWrite::new(BbcnAmedt::new()),
-            bbc1_amaackpd: ReadWrite::new(BbcnAmaackpd::new()),
-            bbc1_amaackt: ReadWrite::new(BbcnAmaackt::new()),
-            bbc1_fskc0: ReadWrite::new(BbcnFskc0::new()),
-            bbc1_fskc1: ReadWrite::new(BbcnFskc1::new()),
-            bbc1_fskc2: ReadWrite::new(BbcnFskc2::new()),
-            bbc1_fskc3: ReadWrite::new(BbcnFskc3::new()),
-            bbc1_fskc4: ReadWrite::new(BbcnFskc4::new()),
-            bbc1_fskpll: ReadWrite::new(BbcnFskpll::new()),
-            bbc1_fsksfd0: ReadWrite::new(BbcnFsksfd::new()),
-            bbc1_fsksfd1: ReadWrite::new(BbcnFsksfd::new()),
-            bbc1_fskphrtx: ReadWrite::new(BbcnFskphrtx::new()),
-            bbc1_fskphrrx: ReadOnly::new(BbcnFskphrrx::new()),
-            bbc1_fskrpc: ReadWrite::new(BbcnFskrpc::new()),
-            bbc1_fskrpcont: ReadWrite::new(BbcnFskrpcont::new()),
-            bbc1_fskrpcofft: ReadWrite::new(BbcnFskrpcofft::new()),
-            bbc1_fskrrxfl: ReadOnly::new(BbcnFskrrxfl::new()),
-            bbc1_fskdm: ReadWrite::new(BbcnFskdm::new()),
-            bbc1_fskpe0: ReadWrite::new(BbcnFskpe::new()),
-            bbc1_fskpe1: ReadWrite::new(BbcnFskpe::new()),
-            bbc1_fskpe2: ReadWrite::new(BbcnFskpe::new()),
-            bbc1_pmuc: ReadWrite::new(BbcnPmuc::new()),
-            bbc1_pmuval: ReadOnly::new(BbcnPmuval::new()),
-            bbc1_pmuqf: ReadOnly::new(BbcnPmuqf::new()),
-            bbc1_pmui: ReadOnly::new(BbcnPmui::new()),
-            bbc1_pmuq: ReadOnly::new(BbcnPmuq::new()),
-            bbc1_cntc: ReadWrite::new(BbcnCntc::new()),
-            bbc1_cnt: ReadOnly::new(BbcnCnt::new()),
+    /// Compares two snapshots, e.g. the expected configuration with a copy
+    /// refreshed from the chip by [`sync`](Self::sync).
+    pub fn diff<'a>(&'a self, new: &'a Radio) -> RadioDiff<'a> {
+        RadioDiff { old: self, new }
+    }
+}
+
+/// Differences between two [`Radio`] snapshots, see [`Radio::diff`]
+pub struct RadioDiff<'a> {
+    old: &'a Radio,
+    new: &'a Radio,
+}
+
+impl<'a> RadioDiff<'a> {
+    /// Every register whose raw value differs, in [`REGISTER_MAP`] order
+    pub fn registers(&self) -> impl Iterator<Item = RegisterDiff<'a>> {
+        let (old, new) = (self.old, self.new);
+        REGISTER_MAP
+            .iter()
+            .zip(old.registers().zip(new.registers()))
+            .filter_map(|(info, (old, new))| {
+                let (old, new) = (old.value(), new.value());
+                (old.bits() != new.bits()).then_some(RegisterDiff { info, old, new })
+            })
+    }
+
+    /// Differing `ReadWrite` registers, leaving out command registers and
+    /// changes limited to status fields
+    pub fn config(&self) -> impl Iterator<Item = RegisterDiff<'a>> {
+        self.registers()
+            .filter(|diff| diff.info.access == Access::ReadWrite && !diff.is_volatile())
+    }
+
+    /// Returns true if both snapshots hold the same values.
+    pub fn is_empty(&self) -> bool {
+        self.registers().next().is_none()
+    }
+}
+
+impl fmt::Display for RadioDiff<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for diff in self.registers() {
+            writeln!(f, "{diff}")?;
         }
+        Ok(())
+    }
+}
+
+/// A register that differs between two snapshots
+#[derive(Clone, Copy)]
+pub struct RegisterDiff<'a> {
+    pub info: &'static RegisterInfo,
+    pub old: &'a dyn RegisterValue,
+    pub new: &'a dyn RegisterValue,
+}
+
+impl RegisterDiff<'_> {
+    /// Returns true if only status fields (IRQS, STATE, RSSI, PC.FCSOK, ...)
+    /// differ, which the chip updates on its own.
+    pub fn is_volatile(&self) -> bool {
+        (self.old.bits() ^ self.new.bits()) & !self.new.volatile_bits() == 0
+    }
+
+    /// Fields whose value differs, as `(old, new)`
+    pub fn fields(&self) -> impl Iterator<Item = (Field, Field)> + '_ {
+        self.old
+            .fields()
+            .zip(self.new.fields())
+            .filter(|(old, new)| old.value != new.value)
+    }
+}
+
+impl fmt::Display for RegisterDiff<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = 2 + 2 * self.info.size;
+        write!(
+            f,
+            "{} {} ({:#06X}): {:#0width$X} -> {:#0width$X}",
+            self.info.block,
+            self.info.name,
+            self.info.address,
+            self.old.bits(),
+            self.new.bits(),
+        )?;
+        if self.is_volatile() {
+            f.write_str(" [volatile]")?;
+        }
+        for (old, new) in self.fields() {
+            write!(
+                f,
+                "\n  {}.{}: {} -> {}",
+                old.register, old.name, old.value, new.value
+            )?;
+        }
+        Ok(())
     }
 }
 
@@ -514,17 +946,63 @@ impl Radio {
 // Frame Buffer Addresses
 // =============================================================================
 
+/// RX Frame Buffer Start (Sub-1GHz)
+pub const BBC0_FBRXS: u16 = 0x2000;
+
 /// TX Frame Buffer Start (Sub-1GHz)
-pub const BBC0_FBTXS: u16 = 0x2000;
+pub const BBC0_FBTXS: u16 = 0x2800;
 
-/// RX Frame Buffer Start (Sub-1GHz)
-pub const BBC0_FBRXS: u16 = 0x3000;
+/// RX Frame Buffer Start (2.4GHz)
+pub const BBC1_FBRXS: u16 = 0x3000;
 
 /// TX Frame Buffer Start (2.4GHz)
-pub const BBC1_FBTXS: u16 = 0x2800;
+pub const BBC1_FBTXS: u16 = 0x3800;
+
+/// Maximum frame length (PSDU) held by a frame buffer
+pub const MAX_FRAME_LEN: usize = 2047;
+
+/// Radio band, selecting a transceiver and the baseband attached to it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Band {
+    /// Sub-1GHz transceiver RF09 and baseband BBC0
+    Rf09,
+    /// 2.4GHz transceiver RF24 and baseband BBC1
+    Rf24,
+}
 
-/// RX Frame Buffer Start (2.4GHz)
-pub const BBC1_FBRXS: u16 = 0x3800;
+impl Band {
+    /// Start of the TX frame buffer
+    pub const fn tx_frame_buffer(self) -> u16 {
+        match self {
+            Self::Rf09 => BBC0_FBTXS,
+            Self::Rf24 => BBC1_FBTXS,
+        }
+    }
+
+    /// Start of the RX frame buffer
+    pub const fn rx_frame_buffer(self) -> u16 {
+        match self {
+            Self::Rf09 => BBC0_FBRXS,
+            Self::Rf24 => BBC1_FBRXS,
+        }
+    }
+
+    /// Address of BBCn_TXFL
+    pub const fn txfl_address(self) -> u16 {
+        match self {
+            Self::Rf09 => 0x0306,
+            Self::Rf24 => 0x0406,
+        }
+    }
+
+    /// Address of BBCn_RXFL
+    pub const fn rxfl_address(self) -> u16 {
+        match self {
+            Self::Rf09 => 0x0304,
+            Self::Rf24 => 0x0404,
+        }
+    }
+}
 
 // =============================================================================
 // Tests
@@ -533,12 +1011,26 @@ pub const BBC1_FBRXS: u16 = 0x3800;
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::string::{String, ToString};
+    use std::{format, vec::Vec};
+
+    #[cfg(feature = "sim")]
+    use crate::sim::Simulator;
+    #[cfg(feature = "alloc")]
+    use embedded_hal_mock::eh1::spi::{Mock, Transaction};
+    #[cfg(feature = "alloc")]
+    use std::vec;
 
+    #[cfg(feature = "alloc")]
     #[test]
     fn test_rf_cfg_write_command() {
         let mut radio = Radio::new();
 
-        radio.rf_cfg.value = radio.rf_cfg.value.with_drv(3).with_irqmm(true);
+        radio.rf_cfg.value = radio
+            .rf_cfg
+            .value
+            .with_drv(DriverStrength::Ma8)
+            .with_irqmm(true);
         assert_eq!(radio.rf_cfg.value.into_bits(), 0b00001011);
         radio.rf_cfg.value.set_irqp(true);
         assert_eq!(radio.rf_cfg.value.into_bits(), 0b00001111);
@@ -555,6 +1047,7 @@ mod tests {
         assert_eq!(cmd[2], 0b00001111); // 0x0F
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
     fn test_rf_cfg_read_command() {
         let radio = Radio::new();
@@ -571,16 +1064,17 @@ mod tests {
         assert_eq!(cmd[2], 0x00); // dummy-byte
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
     fn test_rf09_cmd_write_command() {
         let mut radio = Radio::new();
 
-        assert_eq!(radio.rf09_cmd.value.cmd(), TransceiverCmd::Nop);
+        assert_eq!(radio.rf09.cmd.value.cmd(), TransceiverCmd::Nop);
 
-        radio.rf09_cmd.value.set_cmd(TransceiverCmd::Sleep);
-        assert_eq!(radio.rf09_cmd.value.cmd(), TransceiverCmd::Sleep);
+        radio.rf09.cmd.value.set_cmd(TransceiverCmd::Sleep);
+        assert_eq!(radio.rf09.cmd.value.cmd(), TransceiverCmd::Sleep);
 
-        let cmd = radio.rf09_cmd.write_command();
+        let cmd = radio.rf09.cmd.write_command();
 
         // Should be [header_low, header_high, data]
         assert_eq!(cmd.len(), 3);
@@ -592,11 +1086,12 @@ mod tests {
         assert_eq!(cmd[2], 0x01); // TransceiverCmd::Sleep value (1)
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
     fn test_bbc0_cnt_read_command() {
         let radio = Radio::new();
 
-        let cmd = radio.bbc0_cnt.read_command();
+        let cmd = radio.bbc0.cnt.read_command();
 
         // Should be [header_low, header_high, dummy-0..4]
         assert_eq!(cmd.len(), 6);
@@ -610,14 +1105,15 @@ mod tests {
         assert_eq!(&cmd[2..6], &[0x00, 0x00, 0x00, 0x00]);
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
     fn test_rf09_ccf0_write_command_u16() {
         let mut radio = Radio::new();
 
         // Set a 16-bit channel center frequency value
-        radio.rf09_ccf0.value.set_ccf0(0x1234);
+        radio.rf09.ccf0.value.set_ccf0(0x1234);
 
-        let cmd = radio.rf09_ccf0.write_command();
+        let cmd = radio.rf09.ccf0.write_command();
 
         // Should be [header_low, header_high, data_low, data_high]
         assert_eq!(cmd.len(), 4);
@@ -630,4 +1126,908 @@ mod tests {
         assert_eq!(cmd[2], 0x34); // LSB
         assert_eq!(cmd[3], 0x12); // MSB
     }
+
+    #[test]
+    fn test_reset_values_written_unchanged() {
+        // Reserved bits are written with their reset value, and no
+        // read-only field is set after a reset
+        let radio = Radio::reset_defaults();
+        for (info, reg) in REGISTER_MAP.iter().zip(radio.registers()) {
+            let Some(writable) = reg.as_writable() else {
+                continue;
+            };
+            let mut written = [0u8; 8];
+            writable.write_value(&mut written[..info.size]);
+            assert_eq!(
+                u64::from_le_bytes(written),
+                reg.value().bits(),
+                "{} {}",
+                info.block,
+                info.name
+            );
+        }
+    }
+
+    #[test]
+    fn test_reset_defaults_common() {
+        let radio = Radio::reset_defaults();
+
+        // (register, reset value) from the register summary of the
+        // AT86RF215 datasheet (DS40001914)
+        let table: [(&str, u8, u8); 10] = [
+            ("RF_RST", radio.rf_rst.value.into_bits(), 0x00),
+            ("RF_CFG", radio.rf_cfg.value.into_bits(), 0x08),
+            ("RF_CLKO", radio.rf_clko.value.into_bits(), 0x09),
+            ("RF_BMDVC", radio.rf_bmdvc.value.into_bits(), 0x05),
+            ("RF_XOC", radio.rf_xoc.value.into_bits(), 0x1F),
+            ("RF_IQIFC0", radio.rf_iqifc0.value.into_bits(), 0x16),
+            ("RF_IQIFC1", radio.rf_iqifc1.value.into_bits(), 0x02),
+            ("RF_IQIFC2", radio.rf_iqifc2.value.into_bits(), 0x00),
+            ("RF_PN", radio.rf_pn.value.into_bits(), 0x34),
+            ("RF_VN", radio.rf_vn.value.into_bits(), 0x03),
+        ];
+        for (name, actual, expected) in table {
+            assert_eq!(actual, expected, "{name}");
+        }
+
+        assert_eq!(radio.rf_iqifc1.value.skewdrv(), 2);
+        assert_eq!(radio.rf_pn.value.pn(), DevicePartNumber::AT86RF215);
+    }
+
+    #[test]
+    fn test_reset_defaults_transceivers() {
+        let radio = Radio::reset_defaults();
+
+        let table: [(&str, u8, u8, u8); 20] = [
+            (
+                "IRQM",
+                radio.rf09.irqm.value.into_bits(),
+                radio.rf24.irqm.value.into_bits(),
+                0x00,
+            ),
+            (
+                "AUXS",
+                radio.rf09.auxs.value.into_bits(),
+                radio.rf24.auxs.value.into_bits(),
+                0x02,
+            ),
+            (
+                "STATE",
+                radio.rf09.state.value.into_bits(),
+                radio.rf24.state.value.into_bits(),
+                0x02,
+            ),
+            (
+                "CS",
+                radio.rf09.cs.value.into_bits(),
+                radio.rf24.cs.value.into_bits(),
+                0x08,
+            ),
+            (
+                "RXBWC",
+                radio.rf09.rxbwc.value.into_bits(),
+                radio.rf24.rxbwc.value.into_bits(),
+                0x09,
+            ),
+            (
+                "RXDFE",
+                radio.rf09.rxdfe.value.into_bits(),
+                radio.rf24.rxdfe.value.into_bits(),
+                0x01,
+            ),
+            (
+                "AGCC",
+                radio.rf09.agcc.value.into_bits(),
+                radio.rf24.agcc.value.into_bits(),
+                0x01,
+            ),
+            (
+                "AGCS",
+                radio.rf09.agcs.value.into_bits(),
+                radio.rf24.agcs.value.into_bits(),
+                0x77,
+            ),
+            (
+                "EDC",
+                radio.rf09.edc.value.into_bits(),
+                radio.rf24.edc.value.into_bits(),
+                0x00,
+            ),
+            (
+                "EDD",
+                radio.rf09.edd.value.into_bits(),
+                radio.rf24.edd.value.into_bits(),
+                0x7A,
+            ),
+            (
+                "EDV",
+                radio.rf09.edv.value.into_bits(),
+                radio.rf24.edv.value.into_bits(),
+                0x7F,
+            ),
+            (
+                "TXCUTC",
+                radio.rf09.txcutc.value.into_bits(),
+                radio.rf24.txcutc.value.into_bits(),
+                0x0B,
+            ),
+            (
+                "TXDFE",
+                radio.rf09.txdfe.value.into_bits(),
+                radio.rf24.txdfe.value.into_bits(),
+                0x01,
+            ),
+            (
+                "PAC",
+                radio.rf09.pac.value.into_bits(),
+                radio.rf24.pac.value.into_bits(),
+                0x7F,
+            ),
+            (
+                "PADFE",
+                radio.rf09.padfe.value.into_bits(),
+                radio.rf24.padfe.value.into_bits(),
+                0x00,
+            ),
+            (
+                "PLL",
+                radio.rf09.pll.value.into_bits(),
+                radio.rf24.pll.value.into_bits(),
+                0x09,
+            ),
+            (
+                "TXCI",
+                radio.rf09.txci.value.into_bits(),
+                radio.rf24.txci.value.into_bits(),
+                0x00,
+            ),
+            (
+                "TXCQ",
+                radio.rf09.txcq.value.into_bits(),
+                radio.rf24.txcq.value.into_bits(),
+                0x00,
+            ),
+            (
+                "TXDACI",
+                radio.rf09.txdaci.value.into_bits(),
+                radio.rf24.txdaci.value.into_bits(),
+                0x00,
+            ),
+            (
+                "TXDACQ",
+                radio.rf09.txdacq.value.into_bits(),
+                radio.rf24.txdacq.value.into_bits(),
+                0x00,
+            ),
+        ];
+        for (name, rf09, rf24, expected) in table {
+            assert_eq!(rf09, expected, "RF09_{name}");
+            assert_eq!(rf24, expected, "RF24_{name}");
+        }
+
+        assert_eq!(radio.rf09.auxs.value.pavc(), 2);
+        assert_eq!(radio.rf09.state.value.state(), TransceiverState::TrxOff);
+        assert_eq!(radio.rf09.ccf0.value.into_bits(), 0x8D20);
+        assert_eq!(radio.rf24.ccf0.value.into_bits(), 0x8CF8);
+        assert_eq!(radio.rf09.cnm.value.into_bits(), 0x00);
+        assert_eq!(radio.rf24.rssi.value.rssi(), 127);
+    }
+
+    #[test]
+    fn test_reset_defaults_basebands() {
+        let radio = Radio::reset_defaults();
+
+        let table: [(&str, u8, u8, u8); 12] = [
+            (
+                "IRQM",
+                radio.bbc0.irqm.value.into_bits(),
+                radio.bbc1.irqm.value.into_bits(),
+                0x00,
+            ),
+            (
+                "PC",
+                radio.bbc0.pc.value.into_bits(),
+                radio.bbc1.pc.value.into_bits(),
+                0x1D,
+            ),
+            (
+                "OQPSKC0",
+                radio.bbc0.oqpskc0.value.into_bits(),
+                radio.bbc1.oqpskc0.value.into_bits(),
+                0x03,
+            ),
+            (
+                "OQPSKC1",
+                radio.bbc0.oqpskc1.value.into_bits(),
+                radio.bbc1.oqpskc1.value.into_bits(),
+                0x47,
+            ),
+            (
+                "OQPSKC2",
+                radio.bbc0.oqpskc2.value.into_bits(),
+                radio.bbc1.oqpskc2.value.into_bits(),
+                0x05,
+            ),
+            (
+                "AMEDT",
+                radio.bbc0.amedt.value.into_bits(),
+                radio.bbc1.amedt.value.into_bits(),
+                0xB2,
+            ),
+            (
+                "AMAACKPD",
+                radio.bbc0.amaackpd.value.into_bits(),
+                radio.bbc1.amaackpd.value.into_bits(),
+                0x01,
+            ),
+            (
+                "FSKC0",
+                radio.bbc0.fskc0.value.into_bits(),
+                radio.bbc1.fskc0.value.into_bits(),
+                0xD6,
+            ),
+            (
+                "FSKC3",
+                radio.bbc0.fskc3.value.into_bits(),
+                radio.bbc1.fskc3.value.into_bits(),
+                0x85,
+            ),
+            (
+                "FSKPE0",
+                radio.bbc0.fskpe0.value.into_bits(),
+                radio.bbc1.fskpe0.value.into_bits(),
+                0x02,
+            ),
+            (
+                "FSKPE1",
+                radio.bbc0.fskpe1.value.into_bits(),
+                radio.bbc1.fskpe1.value.into_bits(),
+                0x03,
+            ),
+            (
+                "FSKPE2",
+                radio.bbc0.fskpe2.value.into_bits(),
+                radio.bbc1.fskpe2.value.into_bits(),
+                0xFC,
+            ),
+        ];
+        for (name, bbc0, bbc1, expected) in table {
+            assert_eq!(bbc0, expected, "BBC0_{name}");
+            assert_eq!(bbc1, expected, "BBC1_{name}");
+        }
+
+        assert_eq!(radio.bbc0.fbli.value.into_bits(), 0x07FF);
+        assert_eq!(radio.bbc0.fsksfd0.value.into_bits(), 0x7209);
+        assert_eq!(radio.bbc1.fsksfd1.value.into_bits(), 0xF672);
+        assert_eq!(radio.bbc0.macpidf0.value.into_bits(), 0xFFFF);
+        assert_eq!(radio.bbc1.macshaf3.value.into_bits(), 0xFFFF);
+    }
+
+    #[test]
+    fn test_register_map_no_overlaps() {
+        for (i, a) in REGISTER_MAP.iter().enumerate() {
+            for b in &REGISTER_MAP[i + 1..] {
+                assert!(
+                    !a.overlaps(b),
+                    "{} ({:#06X}) overlaps {} ({:#06X})",
+                    a.name,
+                    a.address,
+                    b.name,
+                    b.address
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_register_map_blocks() {
+        for reg in REGISTER_MAP {
+            let (first, last) = reg.block.address_range();
+            assert!(
+                reg.address >= first && reg.last_address() <= last,
+                "{} ({:#06X}) outside {:?}",
+                reg.name,
+                reg.address,
+                reg.block
+            );
+        }
+    }
+
+    #[test]
+    fn test_register_map_addresses() {
+        // (block, field, datasheet address) for registers that were misplaced before
+        let table = [
+            (Block::Chip, "rf09_irqs", 0x0000),
+            (Block::Chip, "rf24_irqs", 0x0001),
+            (Block::Chip, "bbc0_irqs", 0x0002),
+            (Block::Chip, "bbc1_irqs", 0x0003),
+            (Block::Rf09, "irqm", 0x0100),
+            (Block::Rf09, "auxs", 0x0101),
+            (Block::Rf24, "irqm", 0x0200),
+            (Block::Rf24, "auxs", 0x0201),
+            (Block::Bbc0, "irqm", 0x0300),
+            (Block::Bbc0, "ps", 0x0302),
+            (Block::Bbc1, "irqm", 0x0400),
+            (Block::Bbc1, "ps", 0x0402),
+        ];
+        for (block, name, address) in table {
+            let reg = REGISTER_MAP
+                .iter()
+                .find(|reg| reg.block == block && reg.name == name)
+                .unwrap();
+            assert_eq!(reg.address, address, "{block:?} {name}");
+        }
+
+        let radio = Radio::new();
+        assert_eq!(radio.rf09_irqs.address(), 0x0000);
+        assert_eq!(radio.bbc1_irqs.address(), 0x0003);
+    }
+
+    /// Puts the transceiver into TXPREP on a given channel, independent of the band
+    fn prepare_tx(trx: &mut impl TransceiverRegs, channel: u8) {
+        trx.cnl_mut().set_cnl(channel);
+        trx.cmd_mut().set_cmd(TransceiverCmd::TxPrep);
+    }
+
+    #[test]
+    fn test_transceiver_generic_over_band() {
+        let mut radio = Radio::new();
+
+        prepare_tx(&mut radio.rf09, 3);
+        prepare_tx(&mut radio.rf24, 5);
+
+        assert_eq!(radio.rf09.cnl.write_command_heapless(), [0x81, 0x07, 3]);
+        assert_eq!(radio.rf24.cnl.write_command_heapless(), [0x82, 0x07, 5]);
+        assert_eq!(radio.rf09.cmd.write_command_heapless(), [0x81, 0x03, 0x03]);
+        assert_eq!(radio.rf24.cmd.write_command_heapless(), [0x82, 0x03, 0x03]);
+
+        assert_eq!(radio.rf09.band(), Band::Rf09);
+        assert_eq!(radio.transceiver(Band::Rf24).band(), Band::Rf24);
+        assert_eq!(radio.baseband(Band::Rf24).band(), Band::Rf24);
+        assert_eq!(radio.transceiver(Band::Rf24).cnl().cnl(), 5);
+    }
+
+    #[test]
+    fn test_block_registers_follow_base() {
+        let radio = Radio::new();
+
+        // Same layout at both bases
+        for (rf09, rf24) in Transceiver::<RF09_BASE>::REGISTERS
+            .iter()
+            .zip(Transceiver::<RF24_BASE>::REGISTERS.iter())
+        {
+            assert_eq!(rf09.name, rf24.name);
+            assert_eq!(rf09.address + 0x0100, rf24.address);
+            assert_eq!(rf09.block, Block::Rf09);
+            assert_eq!(rf24.block, Block::Rf24);
+        }
+        for (bbc0, bbc1) in Baseband::<BBC0_BASE>::REGISTERS
+            .iter()
+            .zip(Baseband::<BBC1_BASE>::REGISTERS.iter())
+        {
+            assert_eq!(bbc0.address + 0x0100, bbc1.address);
+        }
+
+        // The only band specific reset value
+        assert_eq!(radio.rf09.ccf0.value.into_bits(), 0x8D20);
+        assert_eq!(radio.rf24.ccf0.value.into_bits(), 0x8CF8);
+
+        // Registers are listed in REGISTER_MAP order
+        let addresses: Vec<u16> = radio
+            .registers()
+            .map(|reg| match reg.as_readable() {
+                Some(reg) => reg.address(),
+                None => reg.as_writable().unwrap().address(),
+            })
+            .collect();
+        let map: Vec<u16> = REGISTER_MAP.iter().map(|reg| reg.address).collect();
+        assert_eq!(addresses, map);
+    }
+
+    #[test]
+    fn test_dump_grouped_by_block() {
+        let mut radio = Radio::new();
+        radio.rf24.rxbwc.value.set_bw(ReceiverBandwidth::Khz400);
+
+        let dump = format!("{}", radio.dump());
+        let headers: Vec<&str> = dump.lines().filter(|l| !l.starts_with(' ')).collect();
+        assert_eq!(headers, ["Common", "RF09", "RF24", "BBC0", "BBC1"]);
+        assert_eq!(dump.lines().count(), REGISTER_COUNT + 5);
+
+        assert!(dump.contains("  0x0006 rf_cfg = 0x08  RF_CFG.DRV = 0 (2mA), RF_CFG.IRQP = 0"));
+        assert!(dump.contains("  0x0209 rxbwc = 0x04  RXBWC.BW = 4 (400kHz @ 500kHz IF)"));
+        assert!(dump.contains("  0x0105 ccf0 = 0x8D20  CCF0.CCF0 = 36128"));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_bridged_writes_fill_gaps() {
+        fn blocks(writes: &BulkWrites) -> Vec<(u16, Vec<u8>)> {
+            writes
+                .get_blocks()
+                .into_iter()
+                .map(|block| (block.start_address, block.data))
+                .collect()
+        }
+
+        let mut radio = Radio::new();
+        radio.rf09.rxbwc.value.set_ifs(true);
+        radio.rf09.agcc.value.set_avgs(2);
+        radio.rf09.edc.value = RfnEdc::from_bits(0x01);
+        assert_eq!(blocks(&radio.dirty_writes()).len(), 3);
+
+        // RXDFE is filled with its shadow value, AGCS..EDC spans read-only RSSI
+        assert_eq!(
+            blocks(&radio.bridged_writes(&WriteCost::default())),
+            [(0x0109, vec![0x19, 0x01, 0x21]), (0x010E, vec![0x01])]
+        );
+
+        // Never worth it when a command costs less than a byte
+        assert_eq!(
+            blocks(&radio.bridged_writes(&WriteCost::new(0, 1))).len(),
+            3
+        );
+
+        // AGCC.RST triggers a reset, so it is not bridged to
+        radio.rf09.agcc.value.set_rst(true);
+        assert_eq!(
+            blocks(&radio.bridged_writes(&WriteCost::default())),
+            [
+                (0x0109, vec![0x19]),
+                (0x010B, vec![0x29]),
+                (0x010E, vec![0x01])
+            ]
+        );
+
+        // Fillers were clean already, marking the writes clean is harmless
+        let mut radio = Radio::new();
+        radio.rf09.rxbwc.value.set_ifs(true);
+        radio.rf09.agcc.value.set_avgs(2);
+        let mut writes = radio.bridged_writes(&WriteCost::default());
+        writes.mark_clean();
+        drop(writes);
+        assert!(!radio.rf09.rxdfe.is_dirty());
+        assert!(radio.dirty_writes().is_empty());
+    }
+
+    #[test]
+    fn test_diff_lists_registers_and_fields() {
+        let expected = Radio::new();
+        assert!(expected.diff(&expected.clone()).is_empty());
+
+        let mut actual = expected.clone();
+        actual.rf09.rxbwc.value.set_bw(ReceiverBandwidth::Khz400);
+        actual.rf09.rxbwc.value.set_ifi(true);
+        actual.bbc1.pc.value.set_pt(PhyType::Oqpsk);
+        actual.rf24.state.value = RfnState::from_bits(0x05);
+        actual.rf09_irqs.value = RfnIrqs::from_bits(0x01);
+
+        let diff = expected.diff(&actual);
+        let names: Vec<_> = diff
+            .registers()
+            .map(|d| (d.info.block, d.info.name, d.is_volatile()))
+            .collect();
+        assert_eq!(
+            names,
+            [
+                (Block::Chip, "rf09_irqs", true),
+                (Block::Rf09, "rxbwc", false),
+                (Block::Rf24, "state", true),
+                (Block::Bbc1, "pc", false),
+            ]
+        );
+
+        // Status registers can be excluded
+        let config: Vec<_> = diff.config().map(|d| d.info.name).collect();
+        assert_eq!(config, ["rxbwc", "pc"]);
+
+        let rxbwc = diff.config().next().unwrap();
+        let fields: Vec<_> = rxbwc.fields().map(|(old, _)| old.name).collect();
+        assert_eq!(fields, ["BW", "IFI"]);
+        assert_eq!(
+            rxbwc.to_string(),
+            "RF09 rxbwc (0x0109): 0x09 -> 0x24\n\
+             \x20 RXBWC.BW: 9 (1250kHz @ 2000kHz IF) -> 4 (400kHz @ 500kHz IF)\n\
+             \x20 RXBWC.IFI: 0 -> 1"
+        );
+
+        let text = diff.to_string();
+        assert!(text.contains("RF24 state (0x0202): 0x02 -> 0x05 [volatile]\n"));
+        assert!(text.contains("  STATE.STATE: 2 (TrxOff) -> 5 (Rx)"));
+        assert!(text.contains("  PC.PT: 1 (MR-FSK) -> 3 (MR-O-QPSK)"));
+    }
+
+    #[test]
+    fn test_diff_ignores_status_fields() {
+        let expected = Radio::new();
+        let mut actual = expected.clone();
+        actual.bbc0.pc.value = BbcnPc::from_bits(expected.bbc0.pc.value.into_bits() | 0x20);
+        actual.rf_vn.value = RfVn::from_bits(0x01);
+
+        // Only the read-only FCSOK flag of PC changed
+        let diff = expected.diff(&actual);
+        let names: Vec<_> = diff
+            .registers()
+            .map(|d| (d.info.name, d.is_volatile()))
+            .collect();
+        assert_eq!(names, [("rf_vn", false), ("pc", true)]);
+        assert_eq!(diff.config().count(), 0);
+    }
+
+    /// Datasheet register summary, common chip registers
+    const CHIP_REGISTERS: [(&str, u16); 14] = [
+        ("RF09_IRQS", 0x0000),
+        ("RF24_IRQS", 0x0001),
+        ("BBC0_IRQS", 0x0002),
+        ("BBC1_IRQS", 0x0003),
+        ("RF_RST", 0x0005),
+        ("RF_CFG", 0x0006),
+        ("RF_CLKO", 0x0007),
+        ("RF_BMDVC", 0x0008),
+        ("RF_XOC", 0x0009),
+        ("RF_IQIFC0", 0x000A),
+        ("RF_IQIFC1", 0x000B),
+        ("RF_IQIFC2", 0x000C),
+        ("RF_PN", 0x000D),
+        ("RF_VN", 0x000E),
+    ];
+
+    /// Datasheet register summary, offsets within the RF09/RF24 blocks
+    const RF_REGISTERS: [(&str, u16); 28] = [
+        ("IRQM", 0x00),
+        ("AUXS", 0x01),
+        ("STATE", 0x02),
+        ("CMD", 0x03),
+        ("CS", 0x04),
+        ("CCF0L", 0x05),
+        ("CCF0H", 0x06),
+        ("CNL", 0x07),
+        ("CNM", 0x08),
+        ("RXBWC", 0x09),
+        ("RXDFE", 0x0A),
+        ("AGCC", 0x0B),
+        ("AGCS", 0x0C),
+        ("RSSI", 0x0D),
+        ("EDC", 0x0E),
+        ("EDD", 0x0F),
+        ("EDV", 0x10),
+        ("RNDV", 0x11),
+        ("TXCUTC", 0x12),
+        ("TXDFE", 0x13),
+        ("PAC", 0x14),
+        ("PADFE", 0x16),
+        ("PLL", 0x21),
+        ("PLLCF", 0x22),
+        ("TXCI", 0x25),
+        ("TXCQ", 0x26),
+        ("TXDACI", 0x27),
+        ("TXDACQ", 0x28),
+    ];
+
+    /// Datasheet register summary, offsets within the BBC0/BBC1 blocks
+    const BBC_REGISTERS: [(&str, u16); 86] = [
+        ("IRQM", 0x00),
+        ("PC", 0x01),
+        ("PS", 0x02),
+        ("RXFLL", 0x04),
+        ("RXFLH", 0x05),
+        ("TXFLL", 0x06),
+        ("TXFLH", 0x07),
+        ("FBLL", 0x08),
+        ("FBLH", 0x09),
+        ("FBLIL", 0x0A),
+        ("FBLIH", 0x0B),
+        ("OFDMPHRTX", 0x0C),
+        ("OFDMPHRRX", 0x0D),
+        ("OFDMC", 0x0E),
+        ("OFDMSW", 0x0F),
+        ("OQPSKC0", 0x10),
+        ("OQPSKC1", 0x11),
+        ("OQPSKC2", 0x12),
+        ("OQPSKC3", 0x13),
+        ("OQPSKPHRTX", 0x14),
+        ("OQPSKPHRRX", 0x15),
+        ("AFC0", 0x20),
+        ("AFC1", 0x21),
+        ("AFFTM", 0x22),
+        ("AFFVM", 0x23),
+        ("AFS", 0x24),
+        ("MACEA0", 0x25),
+        ("MACEA1", 0x26),
+        ("MACEA2", 0x27),
+        ("MACEA3", 0x28),
+        ("MACEA4", 0x29),
+        ("MACEA5", 0x2A),
+        ("MACEA6", 0x2B),
+        ("MACEA7", 0x2C),
+        ("MACPID0F0", 0x2D),
+        ("MACPID1F0", 0x2E),
+        ("MACSHA0F0", 0x2F),
+        ("MACSHA1F0", 0x30),
+        ("MACPID0F1", 0x31),
+        ("MACPID1F1", 0x32),
+        ("MACSHA0F1", 0x33),
+        ("MACSHA1F1", 0x34),
+        ("MACPID0F2", 0x35),
+        ("MACPID1F2", 0x36),
+        ("MACSHA0F2", 0x37),
+        ("MACSHA1F2", 0x38),
+        ("MACPID0F3", 0x39),
+        ("MACPID1F3", 0x3A),
+        ("MACSHA0F3", 0x3B),
+        ("MACSHA1F3", 0x3C),
+        ("AMCS", 0x40),
+        ("AMEDT", 0x41),
+        ("AMAACKPD", 0x42),
+        ("AMAACKTL", 0x43),
+        ("AMAACKTH", 0x44),
+        ("FSKC0", 0x60),
+        ("FSKC1", 0x61),
+        ("FSKC2", 0x62),
+        ("FSKC3", 0x63),
+        ("FSKC4", 0x64),
+        ("FSKPLL", 0x65),
+        ("FSKSFD0L", 0x66),
+        ("FSKSFD0H", 0x67),
+        ("FSKSFD1L", 0x68),
+        ("FSKSFD1H", 0x69),
+        ("FSKPHRTX", 0x6A),
+        ("FSKPHRRX", 0x6B),
+        ("FSKRPC", 0x6C),
+        ("FSKRPCONT", 0x6D),
+        ("FSKRPCOFFT", 0x6E),
+        ("FSKRRXFLL", 0x70),
+        ("FSKRRXFLH", 0x71),
+        ("FSKDM", 0x72),
+        ("FSKPE0", 0x73),
+        ("FSKPE1", 0x74),
+        ("FSKPE2", 0x75),
+        ("PMUC", 0x80),
+        ("PMUVAL", 0x81),
+        ("PMUQF", 0x82),
+        ("PMUI", 0x83),
+        ("PMUQ", 0x84),
+        ("CNTC", 0x90),
+        ("CNT0", 0x91),
+        ("CNT1", 0x92),
+        ("CNT2", 0x93),
+        ("CNT3", 0x94),
+    ];
+
+    #[test]
+    fn test_register_map_coverage() {
+        let mut datasheet: Vec<(String, u16)> = Vec::new();
+        for (name, address) in CHIP_REGISTERS {
+            datasheet.push((name.to_string(), address));
+        }
+        for (prefix, base) in [("RF09", 0x0100), ("RF24", 0x0200)] {
+            for (name, offset) in RF_REGISTERS {
+                datasheet.push((format!("{prefix}_{name}"), base + offset));
+            }
+        }
+        for (prefix, base) in [("BBC0", 0x0300), ("BBC1", 0x0400)] {
+            for (name, offset) in BBC_REGISTERS {
+                datasheet.push((format!("{prefix}_{name}"), base + offset));
+            }
+        }
+
+        let missing: Vec<_> = datasheet
+            .iter()
+            .filter(|(_, address)| {
+                !REGISTER_MAP
+                    .iter()
+                    .any(|reg| (reg.address..=reg.last_address()).contains(address))
+            })
+            .collect();
+        assert!(missing.is_empty(), "not in Radio: {missing:X?}");
+
+        // Every byte of the map is a datasheet register
+        for reg in REGISTER_MAP {
+            for address in reg.address..=reg.last_address() {
+                assert!(
+                    datasheet.iter().any(|(_, a)| *a == address),
+                    "{} covers undocumented address {address:#06X}",
+                    reg.name
+                );
+            }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_flush_writes_only_dirty_registers() {
+        // Nothing changed since reset
+        let mut driver = Driver::new(Mock::new(&[]));
+        let mut radio = Radio::new();
+        radio.flush(&mut driver).unwrap();
+        driver.release().done();
+
+        // rf09_cs and rf09_ccf0 are contiguous
+        let expectations = [
+            Transaction::transaction_start(),
+            Transaction::write_vec(vec![0x81, 0x04, 0x30, 0xC8, 0x8C]),
+            Transaction::transaction_end(),
+            Transaction::transaction_start(),
+            Transaction::write_vec(vec![0x83, 0x01, 0x15]),
+            Transaction::transaction_end(),
+        ];
+        let mut driver = Driver::new(Mock::new(&expectations));
+        radio.rf09.cs.value.set_cs(0x30);
+        radio.rf09.ccf0.value.set_ccf0(0x8CC8);
+        radio.bbc0.pc.value.set_fcst(false);
+        // Set back to its reset value, so not dirty
+        radio.rf_cfg.value.set_drv(DriverStrength::Ma4);
+        radio.rf_cfg.value.set_drv(DriverStrength::Ma2);
+        assert!(radio.rf09.cs.is_dirty());
+        assert!(!radio.rf_cfg.is_dirty());
+
+        radio.flush(&mut driver).unwrap();
+        assert!(!radio.rf09.cs.is_dirty());
+        assert!(!radio.rf09.ccf0.is_dirty());
+        assert!(!radio.bbc0.pc.is_dirty());
+        driver.release().done();
+
+        // Written registers are clean
+        let mut driver = Driver::new(Mock::new(&[]));
+        radio.flush(&mut driver).unwrap();
+        driver.release().done();
+    }
+
+    #[cfg(feature = "sim")]
+    #[test]
+    fn test_sync_refreshes_config_registers() {
+        let mut sim = Simulator::new();
+        sim.poke(0x0006, 0x0F);
+        let mut driver = Driver::new(sim);
+        let mut radio = Radio::new();
+
+        radio.rf_clko.value.set_os(ClockOutput::Mhz16);
+        radio.sync(&mut driver).unwrap();
+
+        assert_eq!(radio.rf_cfg.value.into_bits(), 0x0F);
+        assert_eq!(radio.rf_clko.value.into_bits(), 0x09);
+        assert!(!radio.rf_cfg.is_dirty());
+        assert!(!radio.rf_clko.is_dirty());
+
+        // IRQS are read to clear and left alone, WAKEUP is still pending
+        let sim = driver.release();
+        assert_eq!(sim.peek(0x0000), 0x01);
+        assert!(!radio.rf09_irqs.value.wakeup());
+    }
+
+    /// Fills every register with `pattern`, repeated over its bytes.
+    #[cfg(feature = "serde")]
+    fn fill_registers(radio: &mut Radio, pattern: u8) {
+        let bytes = [pattern; MAX_REGISTER_SIZE];
+        for reg in radio.registers_mut() {
+            if let Some(reg) = reg.as_readable_mut() {
+                let size = reg.size();
+                reg.set_from_bytes(&bytes[..size]);
+            }
+        }
+        radio.rf_rst.value = RfRst::from_bits(pattern);
+        radio.rf09.cmd.value = RfnCmd::from_bits(pattern);
+        radio.rf24.cmd.value = RfnCmd::from_bits(pattern);
+    }
+
+    /// Every named field of every register, as shown by `Display`
+    #[cfg(feature = "serde")]
+    fn field_values(radio: &Radio) -> Vec<String> {
+        radio
+            .registers()
+            .map(|reg| reg.value().to_string())
+            .collect()
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip_preserves_fields() {
+        for pattern in [0x00, 0x5A, 0xA5, 0xFF] {
+            let mut radio = Radio::new();
+            fill_registers(&mut radio, pattern);
+
+            let json = serde_json::to_string(&radio).unwrap();
+            let from_json: Radio = serde_json::from_str(&json).unwrap();
+            assert_eq!(
+                field_values(&from_json),
+                field_values(&radio),
+                "{pattern:#04X}"
+            );
+
+            let toml = toml::to_string(&radio).unwrap();
+            let from_toml: Radio = toml::from_str(&toml).unwrap();
+            assert_eq!(
+                field_values(&from_toml),
+                field_values(&radio),
+                "{pattern:#04X}"
+            );
+        }
+    }
+
+    #[cfg(all(feature = "serde", feature = "sim"))]
+    const BEACON_PROFILE: &str = r#"
+        [rf09.cnl]
+        cnl = 12
+
+        [rf09.pac]
+        txpwr = 20
+        pacur = 3
+
+        [rf09.rxbwc]
+        bw = "Khz400"
+        ifs = false
+        ifi = false
+
+        [bbc0.fskc1]
+        srate = "Khz50"
+        fi = false
+        fskplh = 0
+    "#;
+
+    #[cfg(all(feature = "serde", feature = "sim"))]
+    #[test]
+    fn test_serde_profile_applied_with_bulk_writes() {
+        let mut profile: Radio = toml::from_str(BEACON_PROFILE).unwrap();
+        assert_eq!(profile.rf09.rxbwc.value.bw(), ReceiverBandwidth::Khz400);
+        // Registers missing from the profile keep their reset value
+        assert_eq!(profile.rf24.ccf0.value.into_bits(), 0x8CF8);
+        assert_eq!(profile.rf09.rxdfe.value.into_bits(), 0x01);
+
+        assert!(!profile.rf09.rxbwc.is_dirty());
+
+        // Scramble the chip so every checked byte must have been written
+        let mut sim = Simulator::new();
+        for info in REGISTER_MAP
+            .iter()
+            .filter(|info| info.access == Access::ReadWrite)
+        {
+            for addr in info.address..=info.last_address() {
+                sim.poke(addr, 0xFF);
+            }
+        }
+        let mut driver = Driver::new(sim);
+        driver.write_bulk(&profile.config_writes()).unwrap();
+
+        let sim = driver.release();
+        assert_eq!(sim.peek(0x0107), 12);
+        assert_eq!(sim.peek(0x0114), 0x74);
+        assert_eq!(sim.peek(0x0109), 0x04);
+        assert_eq!(sim.peek(0x0361), 0x00);
+        assert_eq!(sim.peek(0x0205), 0xF8);
+
+        for (info, reg) in REGISTER_MAP.iter().zip(profile.registers()) {
+            if info.access != Access::ReadWrite {
+                continue;
+            }
+            let mut expected = [0u8; MAX_REGISTER_SIZE];
+            reg.as_writable()
+                .unwrap()
+                .write_value(&mut expected[..info.size]);
+            for (i, byte) in expected[..info.size].iter().enumerate() {
+                let addr = info.address + i as u16;
+                assert_eq!(sim.peek(addr), *byte, "{} {addr:#06X}", info.name);
+            }
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_profile_validation() {
+        let too_wide = "[rf09.pac]\ntxpwr = 40\npacur = 0\n";
+        let err = toml::from_str::<Radio>(too_wide).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("TXPWR = 40 does not fit in 5 bits"),
+            "{err}"
+        );
+
+        let unknown_variant = "[rf09.rxbwc]\nbw = \"Khz450\"\nifs = false\nifi = false\n";
+        assert!(toml::from_str::<Radio>(unknown_variant).is_err());
+
+        let unknown_register = "[rf09.rxbw]\nbw = \"Khz400\"\n";
+        assert!(toml::from_str::<Radio>(unknown_register).is_err());
+
+        let missing_field = "[rf09.pac]\ntxpwr = 20\n";
+        assert!(toml::from_str::<Radio>(missing_field).is_err());
+    }
 }
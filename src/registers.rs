@@ -1,19 +1,125 @@
 //! AT86RF215 Register Definitions
 
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+
 use bitfield_struct::bitfield;
+use core::{fmt, iter};
+
+/// Length of the SPI command header
+pub const HEADER_LEN: usize = 2;
+
+/// Size of the largest register (BBCn_MACEA)
+pub const MAX_REGISTER_SIZE: usize = 8;
+
+/// Length of the longest single register command
+pub const MAX_COMMAND_LEN: usize = HEADER_LEN + MAX_REGISTER_SIZE;
+
+/// Size of the SPI address space, registers and frame buffers
+pub const ADDRESS_SPACE_LEN: usize = 0x4000;
+
+/// Number of registers a bulk operation can hold without `alloc`
+#[cfg(not(feature = "alloc"))]
+pub const MAX_BULK_REGISTERS: usize = 64;
+
+/// A caller-provided buffer cannot hold the command
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BufferTooSmall {
+    /// Bytes needed for the command
+    pub required: usize,
+
+    /// Bytes available in the buffer
+    pub available: usize,
+}
+
+/// A register field holds a value the datasheet does not define
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecodeError {
+    /// Name of the field enum
+    pub name: &'static str,
+
+    /// Raw field value
+    pub value: u8,
+}
+
+/// A bulk operation cannot hold more registers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CapacityError;
+
+/// A register cannot be added to [`BulkWrites`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WriteError {
+    /// The collection cannot hold more registers
+    Capacity,
+
+    /// The register overlaps one already added, at `existing`, with different data
+    Conflict { address: u16, existing: u16 },
+}
+
+impl From<CapacityError> for WriteError {
+    fn from(_: CapacityError) -> Self {
+        Self::Capacity
+    }
+}
+
+/// Responses passed to [`BulkReads::parse_responses`] do not cover every register
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResponseError {
+    /// Commands generated, one response is expected for each
+    pub expected: usize,
+
+    /// Responses received
+    pub received: usize,
+
+    /// Registers left with their previous value
+    pub stale: Vec<StaleRegister>,
+}
+
+/// A register not updated by [`BulkReads::parse_responses`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StaleRegister {
+    pub address: u16,
+    pub reason: StaleReason,
+}
+
+/// Why a register was not updated
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StaleReason {
+    /// No response for the command reading it
+    MissingResponse,
 
+    /// The response ends before the register data, `len` of `expected` bytes
+    ShortResponse { len: usize, expected: usize },
+
+    /// The batch failed in strict mode, though this register was covered
+    BatchFailed,
+}
+
+/// Access type of a register, named after its wrapper
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Access {
+    ReadOnly,
+    WriteOnly,
+    ReadWrite,
+}
+
+/// Read-only register
+///
+/// `ADDR` is relative to `BASE`, the start of the register block the
+/// register belongs to. Chip level registers use absolute addresses.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct ReadOnly<T, const ADDR: u16, const SIZE: usize> {
+pub struct ReadOnly<T, const ADDR: u16, const SIZE: usize, const BASE: u16 = 0> {
     pub value: T,
 }
 
-impl<T, const ADDR: u16, const SIZE: usize> ReadOnly<T, ADDR, SIZE> {
+impl<T, const ADDR: u16, const BASE: u16, const SIZE: usize> ReadOnly<T, ADDR, SIZE, BASE> {
     pub const fn new(value: T) -> Self {
         Self { value }
     }
 
     pub const fn address(&self) -> u16 {
-        ADDR
+        BASE + ADDR
     }
 
     pub const fn size(&self) -> usize {
@@ -21,18 +127,19 @@ impl<T, const ADDR: u16, const SIZE: usize> ReadOnly<T, ADDR, SIZE> {
     }
 }
 
+/// Write-only register, `ADDR` is relative to `BASE`
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct WriteOnly<T, const ADDR: u16, const SIZE: usize> {
+pub struct WriteOnly<T, const ADDR: u16, const SIZE: usize, const BASE: u16 = 0> {
     pub value: T,
 }
 
-impl<T, const ADDR: u16, const SIZE: usize> WriteOnly<T, ADDR, SIZE> {
+impl<T, const ADDR: u16, const BASE: u16, const SIZE: usize> WriteOnly<T, ADDR, SIZE, BASE> {
     pub const fn new(value: T) -> Self {
         Self { value }
     }
 
     pub const fn address(&self) -> u16 {
-        ADDR
+        BASE + ADDR
     }
 
     pub const fn size(&self) -> usize {
@@ -40,18 +147,34 @@ impl<T, const ADDR: u16, const SIZE: usize> WriteOnly<T, ADDR, SIZE> {
     }
 }
 
+/// Read/write register, caching the value last known to be in the chip
+///
+/// The register is dirty while `value` differs from that cached value.
+/// `ADDR` is relative to `BASE`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct ReadWrite<T, const ADDR: u16, const SIZE: usize> {
+pub struct ReadWrite<T, const ADDR: u16, const SIZE: usize, const BASE: u16 = 0> {
     pub value: T,
+
+    /// Value last read from or written to the chip
+    synced: T,
 }
 
-impl<T, const ADDR: u16, const SIZE: usize> ReadWrite<T, ADDR, SIZE> {
+impl<T: Copy, const ADDR: u16, const BASE: u16, const SIZE: usize> ReadWrite<T, ADDR, SIZE, BASE> {
+    /// Creates a clean register, assuming the chip already holds `value`.
     pub const fn new(value: T) -> Self {
-        Self { value }
+        Self {
+            value,
+            synced: value,
+        }
+    }
+
+    /// Value last read from or written to the chip
+    pub const fn synced(&self) -> T {
+        self.synced
     }
 
     pub const fn address(&self) -> u16 {
-        ADDR
+        BASE + ADDR
     }
 
     pub const fn size(&self) -> usize {
@@ -59,6 +182,73 @@ impl<T, const ADDR: u16, const SIZE: usize> ReadWrite<T, ADDR, SIZE> {
     }
 }
 
+// A register serializes as its value, a deserialized `ReadWrite` is clean
+#[cfg(feature = "serde")]
+impl<T, const ADDR: u16, const SIZE: usize, const BASE: u16> serde::Serialize
+    for ReadOnly<T, ADDR, SIZE, BASE>
+where
+    T: serde::Serialize,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.value.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T, const ADDR: u16, const SIZE: usize, const BASE: u16> serde::Deserialize<'de>
+    for ReadOnly<T, ADDR, SIZE, BASE>
+where
+    T: serde::Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(Self::new)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T, const ADDR: u16, const SIZE: usize, const BASE: u16> serde::Serialize
+    for WriteOnly<T, ADDR, SIZE, BASE>
+where
+    T: serde::Serialize,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.value.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T, const ADDR: u16, const SIZE: usize, const BASE: u16> serde::Deserialize<'de>
+    for WriteOnly<T, ADDR, SIZE, BASE>
+where
+    T: serde::Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(Self::new)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T, const ADDR: u16, const SIZE: usize, const BASE: u16> serde::Serialize
+    for ReadWrite<T, ADDR, SIZE, BASE>
+where
+    T: serde::Serialize,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.value.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T, const ADDR: u16, const SIZE: usize, const BASE: u16> serde::Deserialize<'de>
+    for ReadWrite<T, ADDR, SIZE, BASE>
+where
+    T: Copy + serde::Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(Self::new)
+    }
+}
+
 pub const fn generate_read_header(addr: u16) -> [u8; 2] {
     let cmd = addr & 0x3FFF;
     [(cmd >> 8) as u8, (cmd & 0xFF) as u8]
@@ -70,6 +260,7 @@ pub const fn generate_write_header(addr: u16) -> [u8; 2] {
     [(cmd >> 8) as u8, (cmd & 0xFF) as u8]
 }
 
+#[cfg(feature = "alloc")]
 #[inline]
 fn build_write_command(header: [u8; 2], value_bytes: Vec<u8>) -> Vec<u8> {
     let mut cmd = Vec::with_capacity(2 + value_bytes.len());
@@ -79,6 +270,7 @@ fn build_write_command(header: [u8; 2], value_bytes: Vec<u8>) -> Vec<u8> {
     cmd
 }
 
+#[cfg(feature = "alloc")]
 #[inline]
 fn build_read_command(header: [u8; 2], size: usize) -> Vec<u8> {
     let mut cmd = Vec::with_capacity(2 + size);
@@ -88,16 +280,134 @@ fn build_read_command(header: [u8; 2], size: usize) -> Vec<u8> {
     cmd
 }
 
+/// Returns `buf[..len]`, or an error if `buf` is shorter than `len`.
+#[inline]
+fn command_slice(buf: &mut [u8], len: usize) -> Result<&mut [u8], BufferTooSmall> {
+    let available = buf.len();
+    buf.get_mut(..len).ok_or(BufferTooSmall {
+        required: len,
+        available,
+    })
+}
+
 pub trait Writable {
     fn address(&self) -> u16;
 
     fn size(&self) -> usize;
 
-    fn value_bytes(&self) -> Vec<u8>;
+    /// Copies the little-endian register value into `buf`, which is `size()` bytes long.
+    fn write_value(&self, buf: &mut [u8]);
+
+    /// Returns true if the value was modified since it was last synced with the chip.
+    ///
+    /// Only `ReadWrite` registers track this, write-only registers are never dirty.
+    fn is_dirty(&self) -> bool {
+        false
+    }
+
+    /// Records the current value as written to the chip.
+    fn mark_clean(&mut self) {}
+
+    /// When [`BulkWrites`] writes the register, relative to the others.
+    fn write_stage(&self) -> WriteStage {
+        WriteStage::Config
+    }
+
+    /// Returns true if writing the current value triggers an action, like a
+    /// transceiver command or a chip reset.
+    fn triggers(&self) -> bool {
+        false
+    }
+
+    #[cfg(feature = "alloc")]
+    fn value_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![0x00; self.size()];
+        self.write_value(&mut bytes);
+        bytes
+    }
 
+    #[cfg(feature = "alloc")]
     fn write_command(&self) -> Vec<u8> {
         build_write_command(generate_write_header(self.address()), self.value_bytes())
     }
+
+    /// Builds the write command into `buf` and returns its length.
+    fn write_command_into(&self, buf: &mut [u8]) -> Result<usize, BufferTooSmall> {
+        let cmd = command_slice(buf, HEADER_LEN + self.size())?;
+        cmd[..HEADER_LEN].copy_from_slice(&generate_write_header(self.address()));
+        self.write_value(&mut cmd[HEADER_LEN..]);
+        Ok(cmd.len())
+    }
+
+    /// Builds the write command in a fixed capacity vector.
+    fn write_command_heapless(&self) -> heapless::Vec<u8, MAX_COMMAND_LEN> {
+        let mut cmd = heapless::Vec::new();
+        cmd.resize_default(HEADER_LEN + self.size())
+            .expect("register larger than MAX_REGISTER_SIZE");
+        self.write_command_into(&mut cmd).unwrap();
+        cmd
+    }
+}
+
+/// Bits of a register value that are written to the chip
+///
+/// Derived from the field definitions: read-only fields are written as 0 so a
+/// read-modify-write never writes status bits back, and reserved bits are
+/// written with their default from the bitfield, which is the reset value.
+pub trait WriteMask {
+    /// Writable fields
+    const WRITABLE: u64;
+
+    /// Value of the reserved bits
+    const RESERVED: u64;
+
+    /// Returns the register value as written to the chip.
+    fn write_bits(bits: u64) -> u64 {
+        (bits & Self::WRITABLE) | Self::RESERVED
+    }
+}
+
+/// [`WriteMask::write_bits`], usable in `const` items
+const fn masked_bits<T: WriteMask>(bits: u64) -> u64 {
+    (bits & T::WRITABLE) | T::RESERVED
+}
+
+/// Mask of a field with `bits` bits at `offset`
+const fn field_mask(bits: usize, offset: usize) -> u64 {
+    (u64::MAX >> (64 - bits)) << offset
+}
+
+/// Order in which [`BulkWrites`] writes registers, earlier stages first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum WriteStage {
+    /// Configuration
+    Config,
+    /// Applies the configuration written before it, e.g. RFn_CNM for the channel
+    Commit,
+    /// Triggers an action once configured, e.g. RFn_CMD
+    Command,
+}
+
+impl WriteStage {
+    /// Every stage, in write order
+    pub const ALL: [Self; 3] = [Self::Config, Self::Commit, Self::Command];
+}
+
+/// Write stage of a register type
+pub trait WriteOrder {
+    const STAGE: WriteStage;
+}
+
+/// Side effects of accessing a register, beyond reading or storing its value
+pub trait SideEffects {
+    /// Reading the register clears it
+    const CLEARS_ON_READ: bool;
+
+    /// Fields that trigger an action when written with a non-zero value
+    const TRIGGER: u64;
+
+    /// Read-only status fields the chip updates on its own
+    const VOLATILE: u64;
 }
 
 pub trait Readable {
@@ -107,114 +417,335 @@ pub trait Readable {
 
     fn set_from_bytes(&mut self, bytes: &[u8]);
 
+    /// Returns true if reading the register clears it, like the IRQS
+    /// interrupt status.
+    fn clears_on_read(&self) -> bool {
+        false
+    }
+
+    #[cfg(feature = "alloc")]
     fn read_command(&self) -> Vec<u8> {
         build_read_command(generate_read_header(self.address()), self.size())
     }
+
+    /// Builds the read command into `buf` and returns its length.
+    fn read_command_into(&self, buf: &mut [u8]) -> Result<usize, BufferTooSmall> {
+        let cmd = command_slice(buf, HEADER_LEN + self.size())?;
+        cmd[..HEADER_LEN].copy_from_slice(&generate_read_header(self.address()));
+        cmd[HEADER_LEN..].fill(0x00);
+        Ok(cmd.len())
+    }
+
+    /// Builds the read command in a fixed capacity vector.
+    fn read_command_heapless(&self) -> heapless::Vec<u8, MAX_COMMAND_LEN> {
+        let mut cmd = heapless::Vec::new();
+        cmd.resize_default(HEADER_LEN + self.size())
+            .expect("register larger than MAX_REGISTER_SIZE");
+        self.read_command_into(&mut cmd).unwrap();
+        cmd
+    }
+}
+
+/// Object safe view of any register wrapper, used to walk a whole register map
+pub trait Register {
+    fn access(&self) -> Access;
+
+    fn as_writable(&self) -> Option<&dyn Writable>;
+
+    fn as_writable_mut(&mut self) -> Option<&mut dyn Writable>;
+
+    fn as_readable(&self) -> Option<&dyn Readable>;
+
+    fn as_readable_mut(&mut self) -> Option<&mut dyn Readable>;
+
+    /// Current value, for printing
+    fn value(&self) -> &dyn RegisterValue;
+}
+
+impl<T, const ADDR: u16, const BASE: u16, const SIZE: usize> Register
+    for ReadOnly<T, ADDR, SIZE, BASE>
+where
+    T: RegisterValue,
+    Self: Readable,
+{
+    fn access(&self) -> Access {
+        Access::ReadOnly
+    }
+    fn as_writable(&self) -> Option<&dyn Writable> {
+        None
+    }
+    fn as_writable_mut(&mut self) -> Option<&mut dyn Writable> {
+        None
+    }
+    fn as_readable(&self) -> Option<&dyn Readable> {
+        Some(self)
+    }
+    fn as_readable_mut(&mut self) -> Option<&mut dyn Readable> {
+        Some(self)
+    }
+    fn value(&self) -> &dyn RegisterValue {
+        &self.value
+    }
+}
+
+impl<T, const ADDR: u16, const BASE: u16, const SIZE: usize> Register
+    for WriteOnly<T, ADDR, SIZE, BASE>
+where
+    T: RegisterValue,
+    Self: Writable,
+{
+    fn access(&self) -> Access {
+        Access::WriteOnly
+    }
+    fn as_writable(&self) -> Option<&dyn Writable> {
+        Some(self)
+    }
+    fn as_writable_mut(&mut self) -> Option<&mut dyn Writable> {
+        Some(self)
+    }
+    fn as_readable(&self) -> Option<&dyn Readable> {
+        None
+    }
+    fn as_readable_mut(&mut self) -> Option<&mut dyn Readable> {
+        None
+    }
+    fn value(&self) -> &dyn RegisterValue {
+        &self.value
+    }
+}
+
+impl<T, const ADDR: u16, const BASE: u16, const SIZE: usize> Register
+    for ReadWrite<T, ADDR, SIZE, BASE>
+where
+    T: RegisterValue,
+    Self: Readable + Writable,
+{
+    fn access(&self) -> Access {
+        Access::ReadWrite
+    }
+    fn as_writable(&self) -> Option<&dyn Writable> {
+        Some(self)
+    }
+    fn as_writable_mut(&mut self) -> Option<&mut dyn Writable> {
+        Some(self)
+    }
+    fn as_readable(&self) -> Option<&dyn Readable> {
+        Some(self)
+    }
+    fn as_readable_mut(&mut self) -> Option<&mut dyn Readable> {
+        Some(self)
+    }
+    fn value(&self) -> &dyn RegisterValue {
+        &self.value
+    }
 }
 
 // =============================================================================
 // Writable implementations for each underlying integer size
 // =============================================================================
 
-impl<T: Copy + Into<u8>, const ADDR: u16> Writable for WriteOnly<T, ADDR, 1> {
+impl<T: Copy + Into<u8> + WriteMask + WriteOrder + SideEffects, const ADDR: u16, const BASE: u16>
+    Writable for WriteOnly<T, ADDR, 1, BASE>
+{
     fn address(&self) -> u16 {
-        ADDR
+        BASE + ADDR
     }
     fn size(&self) -> usize {
         1
     }
-    fn value_bytes(&self) -> Vec<u8> {
-        vec![self.value.into()]
+    fn write_value(&self, buf: &mut [u8]) {
+        let val: u8 = self.value.into();
+        buf[0] = T::write_bits(val.into()) as u8;
+    }
+    fn write_stage(&self) -> WriteStage {
+        T::STAGE
+    }
+    fn triggers(&self) -> bool {
+        let val: u8 = self.value.into();
+        T::write_bits(val.into()) & T::TRIGGER != 0
     }
 }
 
-impl<T: Copy + Into<u8>, const ADDR: u16> Writable for ReadWrite<T, ADDR, 1> {
+impl<T: Copy + Into<u8> + WriteMask + WriteOrder + SideEffects, const ADDR: u16, const BASE: u16>
+    Writable for ReadWrite<T, ADDR, 1, BASE>
+{
     fn address(&self) -> u16 {
-        ADDR
+        BASE + ADDR
     }
     fn size(&self) -> usize {
         1
     }
-    fn value_bytes(&self) -> Vec<u8> {
-        vec![self.value.into()]
+    fn write_value(&self, buf: &mut [u8]) {
+        let val: u8 = self.value.into();
+        buf[0] = T::write_bits(val.into()) as u8;
+    }
+    fn write_stage(&self) -> WriteStage {
+        T::STAGE
+    }
+    fn triggers(&self) -> bool {
+        let val: u8 = self.value.into();
+        T::write_bits(val.into()) & T::TRIGGER != 0
+    }
+    fn is_dirty(&self) -> bool {
+        self.value.into() != self.synced.into()
+    }
+    fn mark_clean(&mut self) {
+        self.synced = self.value;
     }
 }
 
-impl<T: Copy + Into<u16>, const ADDR: u16> Writable for WriteOnly<T, ADDR, 2> {
+impl<T: Copy + Into<u16> + WriteMask + WriteOrder + SideEffects, const ADDR: u16, const BASE: u16>
+    Writable for WriteOnly<T, ADDR, 2, BASE>
+{
     fn address(&self) -> u16 {
-        ADDR
+        BASE + ADDR
     }
     fn size(&self) -> usize {
         2
     }
-    fn value_bytes(&self) -> Vec<u8> {
+    fn write_value(&self, buf: &mut [u8]) {
+        let val: u16 = self.value.into();
+        let val = T::write_bits(val.into()) as u16;
+        buf.copy_from_slice(&val.to_le_bytes());
+    }
+    fn write_stage(&self) -> WriteStage {
+        T::STAGE
+    }
+    fn triggers(&self) -> bool {
         let val: u16 = self.value.into();
-        val.to_le_bytes().to_vec()
+        T::write_bits(val.into()) & T::TRIGGER != 0
     }
 }
 
-impl<T: Copy + Into<u16>, const ADDR: u16> Writable for ReadWrite<T, ADDR, 2> {
+impl<T: Copy + Into<u16> + WriteMask + WriteOrder + SideEffects, const ADDR: u16, const BASE: u16>
+    Writable for ReadWrite<T, ADDR, 2, BASE>
+{
     fn address(&self) -> u16 {
-        ADDR
+        BASE + ADDR
     }
     fn size(&self) -> usize {
         2
     }
-    fn value_bytes(&self) -> Vec<u8> {
+    fn write_value(&self, buf: &mut [u8]) {
+        let val: u16 = self.value.into();
+        let val = T::write_bits(val.into()) as u16;
+        buf.copy_from_slice(&val.to_le_bytes());
+    }
+    fn write_stage(&self) -> WriteStage {
+        T::STAGE
+    }
+    fn triggers(&self) -> bool {
+        let val: u16 = self.value.into();
+        T::write_bits(val.into()) & T::TRIGGER != 0
+    }
+    fn is_dirty(&self) -> bool {
         let val: u16 = self.value.into();
-        val.to_le_bytes().to_vec()
+        val != self.synced.into()
+    }
+    fn mark_clean(&mut self) {
+        self.synced = self.value;
     }
 }
 
-impl<T: Copy + Into<u32>, const ADDR: u16> Writable for WriteOnly<T, ADDR, 4> {
+impl<T: Copy + Into<u32> + WriteMask + WriteOrder + SideEffects, const ADDR: u16, const BASE: u16>
+    Writable for WriteOnly<T, ADDR, 4, BASE>
+{
     fn address(&self) -> u16 {
-        ADDR
+        BASE + ADDR
     }
     fn size(&self) -> usize {
         4
     }
-    fn value_bytes(&self) -> Vec<u8> {
+    fn write_value(&self, buf: &mut [u8]) {
+        let val: u32 = self.value.into();
+        let val = T::write_bits(val.into()) as u32;
+        buf.copy_from_slice(&val.to_le_bytes());
+    }
+    fn write_stage(&self) -> WriteStage {
+        T::STAGE
+    }
+    fn triggers(&self) -> bool {
         let val: u32 = self.value.into();
-        val.to_le_bytes().to_vec()
+        T::write_bits(val.into()) & T::TRIGGER != 0
     }
 }
 
-impl<T: Copy + Into<u32>, const ADDR: u16> Writable for ReadWrite<T, ADDR, 4> {
+impl<T: Copy + Into<u32> + WriteMask + WriteOrder + SideEffects, const ADDR: u16, const BASE: u16>
+    Writable for ReadWrite<T, ADDR, 4, BASE>
+{
     fn address(&self) -> u16 {
-        ADDR
+        BASE + ADDR
     }
     fn size(&self) -> usize {
         4
     }
-    fn value_bytes(&self) -> Vec<u8> {
+    fn write_value(&self, buf: &mut [u8]) {
+        let val: u32 = self.value.into();
+        let val = T::write_bits(val.into()) as u32;
+        buf.copy_from_slice(&val.to_le_bytes());
+    }
+    fn write_stage(&self) -> WriteStage {
+        T::STAGE
+    }
+    fn triggers(&self) -> bool {
         let val: u32 = self.value.into();
-        val.to_le_bytes().to_vec()
+        T::write_bits(val.into()) & T::TRIGGER != 0
+    }
+    fn is_dirty(&self) -> bool {
+        let val: u32 = self.value.into();
+        val != self.synced.into()
+    }
+    fn mark_clean(&mut self) {
+        self.synced = self.value;
     }
 }
 
-impl<T: Copy + Into<u64>, const ADDR: u16> Writable for WriteOnly<T, ADDR, 8> {
+impl<T: Copy + Into<u64> + WriteMask + WriteOrder + SideEffects, const ADDR: u16, const BASE: u16>
+    Writable for WriteOnly<T, ADDR, 8, BASE>
+{
     fn address(&self) -> u16 {
-        ADDR
+        BASE + ADDR
     }
     fn size(&self) -> usize {
         8
     }
-    fn value_bytes(&self) -> Vec<u8> {
-        let val: u64 = self.value.into();
-        val.to_le_bytes().to_vec()
+    fn write_value(&self, buf: &mut [u8]) {
+        let val = T::write_bits(self.value.into());
+        buf.copy_from_slice(&val.to_le_bytes());
+    }
+    fn write_stage(&self) -> WriteStage {
+        T::STAGE
+    }
+    fn triggers(&self) -> bool {
+        T::write_bits(self.value.into()) & T::TRIGGER != 0
     }
 }
 
-impl<T: Copy + Into<u64>, const ADDR: u16> Writable for ReadWrite<T, ADDR, 8> {
+impl<T: Copy + Into<u64> + WriteMask + WriteOrder + SideEffects, const ADDR: u16, const BASE: u16>
+    Writable for ReadWrite<T, ADDR, 8, BASE>
+{
     fn address(&self) -> u16 {
-        ADDR
+        BASE + ADDR
     }
     fn size(&self) -> usize {
         8
     }
-    fn value_bytes(&self) -> Vec<u8> {
+    fn write_value(&self, buf: &mut [u8]) {
+        let val = T::write_bits(self.value.into());
+        buf.copy_from_slice(&val.to_le_bytes());
+    }
+    fn write_stage(&self) -> WriteStage {
+        T::STAGE
+    }
+    fn triggers(&self) -> bool {
+        T::write_bits(self.value.into()) & T::TRIGGER != 0
+    }
+    fn is_dirty(&self) -> bool {
         let val: u64 = self.value.into();
-        val.to_le_bytes().to_vec()
+        val != self.synced.into()
+    }
+    fn mark_clean(&mut self) {
+        self.synced = self.value;
     }
 }
 
@@ -222,9 +753,11 @@ impl<T: Copy + Into<u64>, const ADDR: u16> Writable for ReadWrite<T, ADDR, 8> {
 // Readable implementations for each underlying integer size
 // =============================================================================
 
-impl<T: Copy + From<u8>, const ADDR: u16> Readable for ReadOnly<T, ADDR, 1> {
+impl<T: Copy + From<u8> + SideEffects, const ADDR: u16, const BASE: u16> Readable
+    for ReadOnly<T, ADDR, 1, BASE>
+{
     fn address(&self) -> u16 {
-        ADDR
+        BASE + ADDR
     }
     fn size(&self) -> usize {
         1
@@ -234,11 +767,16 @@ impl<T: Copy + From<u8>, const ADDR: u16> Readable for ReadOnly<T, ADDR, 1> {
             self.value = T::from(bytes[0]);
         }
     }
+    fn clears_on_read(&self) -> bool {
+        T::CLEARS_ON_READ
+    }
 }
 
-impl<T: Copy + From<u8>, const ADDR: u16> Readable for ReadWrite<T, ADDR, 1> {
+impl<T: Copy + From<u8> + SideEffects, const ADDR: u16, const BASE: u16> Readable
+    for ReadWrite<T, ADDR, 1, BASE>
+{
     fn address(&self) -> u16 {
-        ADDR
+        BASE + ADDR
     }
     fn size(&self) -> usize {
         1
@@ -246,13 +784,19 @@ impl<T: Copy + From<u8>, const ADDR: u16> Readable for ReadWrite<T, ADDR, 1> {
     fn set_from_bytes(&mut self, bytes: &[u8]) {
         if !bytes.is_empty() {
             self.value = T::from(bytes[0]);
+            self.synced = self.value;
         }
     }
+    fn clears_on_read(&self) -> bool {
+        T::CLEARS_ON_READ
+    }
 }
 
-impl<T: Copy + From<u16>, const ADDR: u16> Readable for ReadOnly<T, ADDR, 2> {
+impl<T: Copy + From<u16> + SideEffects, const ADDR: u16, const BASE: u16> Readable
+    for ReadOnly<T, ADDR, 2, BASE>
+{
     fn address(&self) -> u16 {
-        ADDR
+        BASE + ADDR
     }
     fn size(&self) -> usize {
         2
@@ -262,11 +806,16 @@ impl<T: Copy + From<u16>, const ADDR: u16> Readable for ReadOnly<T, ADDR, 2> {
             self.value = T::from(u16::from_le_bytes(bytes[..2].try_into().unwrap()));
         }
     }
+    fn clears_on_read(&self) -> bool {
+        T::CLEARS_ON_READ
+    }
 }
 
-impl<T: Copy + From<u16>, const ADDR: u16> Readable for ReadWrite<T, ADDR, 2> {
+impl<T: Copy + From<u16> + SideEffects, const ADDR: u16, const BASE: u16> Readable
+    for ReadWrite<T, ADDR, 2, BASE>
+{
     fn address(&self) -> u16 {
-        ADDR
+        BASE + ADDR
     }
     fn size(&self) -> usize {
         2
@@ -274,13 +823,19 @@ impl<T: Copy + From<u16>, const ADDR: u16> Readable for ReadWrite<T, ADDR, 2> {
     fn set_from_bytes(&mut self, bytes: &[u8]) {
         if bytes.len() >= 2 {
             self.value = T::from(u16::from_le_bytes(bytes[..2].try_into().unwrap()));
+            self.synced = self.value;
         }
     }
+    fn clears_on_read(&self) -> bool {
+        T::CLEARS_ON_READ
+    }
 }
 
-impl<T: Copy + From<u32>, const ADDR: u16> Readable for ReadOnly<T, ADDR, 4> {
+impl<T: Copy + From<u32> + SideEffects, const ADDR: u16, const BASE: u16> Readable
+    for ReadOnly<T, ADDR, 4, BASE>
+{
     fn address(&self) -> u16 {
-        ADDR
+        BASE + ADDR
     }
     fn size(&self) -> usize {
         4
@@ -290,11 +845,16 @@ impl<T: Copy + From<u32>, const ADDR: u16> Readable for ReadOnly<T, ADDR, 4> {
             self.value = T::from(u32::from_le_bytes(bytes[..4].try_into().unwrap()));
         }
     }
+    fn clears_on_read(&self) -> bool {
+        T::CLEARS_ON_READ
+    }
 }
 
-impl<T: Copy + From<u32>, const ADDR: u16> Readable for ReadWrite<T, ADDR, 4> {
+impl<T: Copy + From<u32> + SideEffects, const ADDR: u16, const BASE: u16> Readable
+    for ReadWrite<T, ADDR, 4, BASE>
+{
     fn address(&self) -> u16 {
-        ADDR
+        BASE + ADDR
     }
     fn size(&self) -> usize {
         4
@@ -302,13 +862,19 @@ impl<T: Copy + From<u32>, const ADDR: u16> Readable for ReadWrite<T, ADDR, 4> {
     fn set_from_bytes(&mut self, bytes: &[u8]) {
         if bytes.len() >= 4 {
             self.value = T::from(u32::from_le_bytes(bytes[..4].try_into().unwrap()));
+            self.synced = self.value;
         }
     }
+    fn clears_on_read(&self) -> bool {
+        T::CLEARS_ON_READ
+    }
 }
 
-impl<T: Copy + From<u64>, const ADDR: u16> Readable for ReadOnly<T, ADDR, 8> {
+impl<T: Copy + From<u64> + SideEffects, const ADDR: u16, const BASE: u16> Readable
+    for ReadOnly<T, ADDR, 8, BASE>
+{
     fn address(&self) -> u16 {
-        ADDR
+        BASE + ADDR
     }
     fn size(&self) -> usize {
         8
@@ -318,11 +884,16 @@ impl<T: Copy + From<u64>, const ADDR: u16> Readable for ReadOnly<T, ADDR, 8> {
             self.value = T::from(u64::from_le_bytes(bytes[..8].try_into().unwrap()));
         }
     }
+    fn clears_on_read(&self) -> bool {
+        T::CLEARS_ON_READ
+    }
 }
 
-impl<T: Copy + From<u64>, const ADDR: u16> Readable for ReadWrite<T, ADDR, 8> {
+impl<T: Copy + From<u64> + SideEffects, const ADDR: u16, const BASE: u16> Readable
+    for ReadWrite<T, ADDR, 8, BASE>
+{
     fn address(&self) -> u16 {
-        ADDR
+        BASE + ADDR
     }
     fn size(&self) -> usize {
         8
@@ -330,253 +901,835 @@ impl<T: Copy + From<u64>, const ADDR: u16> Readable for ReadWrite<T, ADDR, 8> {
     fn set_from_bytes(&mut self, bytes: &[u8]) {
         if bytes.len() >= 8 {
             self.value = T::from(u64::from_le_bytes(bytes[..8].try_into().unwrap()));
+            self.synced = self.value;
         }
     }
+    fn clears_on_read(&self) -> bool {
+        T::CLEARS_ON_READ
+    }
 }
 
 // =============================================================================
-// Bulk Register Write
+// Compile-time commands for each register size
 // =============================================================================
 
-#[derive(Debug, Clone)]
-pub struct BulkWriteBlock {
-    pub start_address: u16,
-
-    pub data: Vec<u8>,
+/// Builds a read command of `N` bytes at compile time.
+pub const fn read_command_array<const N: usize>(addr: u16) -> [u8; N] {
+    assert!(N >= HEADER_LEN);
+    let header = generate_read_header(addr);
+    let mut cmd = [0x00; N];
+    cmd[0] = header[0];
+    cmd[1] = header[1];
+    cmd
 }
 
-pub struct BulkWrites<'a> {
-    registers: Vec<&'a mut dyn Writable>,
+/// Builds a write command of `N` bytes for the little-endian `value` at compile time.
+pub const fn write_command_array<const N: usize>(addr: u16, value: &[u8]) -> [u8; N] {
+    assert!(N == HEADER_LEN + value.len());
+    let header = generate_write_header(addr);
+    let mut cmd = [0x00; N];
+    cmd[0] = header[0];
+    cmd[1] = header[1];
+    let mut i = 0;
+    while i < value.len() {
+        cmd[HEADER_LEN + i] = value[i];
+        i += 1;
+    }
+    cmd
 }
 
-impl<'a> BulkWrites<'a> {
-    pub fn new() -> Self {
-        Self {
-            registers: Vec::new(),
-        }
-    }
+impl<T, const ADDR: u16, const BASE: u16> ReadOnly<T, ADDR, 1, BASE> {
+    /// Read command, usable in `const` and `static` items
+    pub const READ_COMMAND: [u8; 3] = read_command_array(BASE + ADDR);
+}
 
-    pub fn add<W: Writable>(&mut self, reg: &'a mut W) {
-        self.registers.push(reg);
+impl<T, const ADDR: u16, const BASE: u16> WriteOnly<T, ADDR, 1, BASE> {
+    /// Builds the write command for the raw register value `bits`, masked by
+    /// [`WriteMask`] like the runtime commands, at compile time.
+    pub const fn write_command_array(bits: u8) -> [u8; 3]
+    where
+        T: WriteMask,
+    {
+        let bits = masked_bits::<T>(bits as u64) as u8;
+        write_command_array(BASE + ADDR, &bits.to_le_bytes())
     }
+}
 
-    /// Generates write commands for contiguous registers in the collection.
-    ///
-    /// # Returns
-    /// A vector of SPI commands. Each command consists of a 2-byte header
-    /// followed by data bytes for contiguous registers.
-    pub fn generate_commands(&self) -> Vec<Vec<u8>> {
-        let blocks = self.compute_blocks();
+impl<T, const ADDR: u16, const BASE: u16> ReadWrite<T, ADDR, 1, BASE> {
+    /// Read command, usable in `const` and `static` items
+    pub const READ_COMMAND: [u8; 3] = read_command_array(BASE + ADDR);
 
-        blocks
-            .into_iter()
-            .map(|block| {
-                build_write_command(generate_write_header(block.start_address), block.data)
-            })
-            .collect()
+    /// Builds the write command for the raw register value `bits`, masked by
+    /// [`WriteMask`] like the runtime commands, at compile time.
+    pub const fn write_command_array(bits: u8) -> [u8; 3]
+    where
+        T: WriteMask,
+    {
+        let bits = masked_bits::<T>(bits as u64) as u8;
+        write_command_array(BASE + ADDR, &bits.to_le_bytes())
     }
+}
 
-    /// Computes the contiguous blocks for writing.
-    fn compute_blocks(&self) -> Vec<BulkWriteBlock> {
-        if self.registers.is_empty() {
-            return Vec::new();
-        }
+impl<T, const ADDR: u16, const BASE: u16> ReadOnly<T, ADDR, 2, BASE> {
+    /// Read command, usable in `const` and `static` items
+    pub const READ_COMMAND: [u8; 4] = read_command_array(BASE + ADDR);
+}
 
-        // Create sortable info: (address, size, data, original_index)
-        let mut reg_infos: Vec<_> = self
-            .registers
-            .iter()
-            .enumerate()
-            .map(|(idx, reg)| (reg.address(), reg.size(), reg.value_bytes(), idx))
-            .collect();
+impl<T, const ADDR: u16, const BASE: u16> WriteOnly<T, ADDR, 2, BASE> {
+    /// Builds the write command for the raw register value `bits`, masked by
+    /// [`WriteMask`] like the runtime commands, at compile time.
+    pub const fn write_command_array(bits: u16) -> [u8; 4]
+    where
+        T: WriteMask,
+    {
+        let bits = masked_bits::<T>(bits as u64) as u16;
+        write_command_array(BASE + ADDR, &bits.to_le_bytes())
+    }
+}
 
-        // Sort by address
-        reg_infos.sort_by_key(|(addr, _, _, _)| *addr);
+impl<T, const ADDR: u16, const BASE: u16> ReadWrite<T, ADDR, 2, BASE> {
+    /// Read command, usable in `const` and `static` items
+    pub const READ_COMMAND: [u8; 4] = read_command_array(BASE + ADDR);
 
-        let mut blocks = Vec::new();
-        let (first_addr, _first_size, first_data, _first_idx) = reg_infos.remove(0);
-        let mut current_block = BulkWriteBlock {
-            start_address: first_addr,
-            data: first_data,
-        };
+    /// Builds the write command for the raw register value `bits`, masked by
+    /// [`WriteMask`] like the runtime commands, at compile time.
+    pub const fn write_command_array(bits: u16) -> [u8; 4]
+    where
+        T: WriteMask,
+    {
+        let bits = masked_bits::<T>(bits as u64) as u16;
+        write_command_array(BASE + ADDR, &bits.to_le_bytes())
+    }
+}
 
-        for (addr, _size, data, _idx) in reg_infos {
-            let expected_next_addr = current_block.start_address + current_block.data.len() as u16;
-
-            if addr == expected_next_addr {
-                // Contiguous: append to current block
-                current_block.data.extend(data);
-            } else {
-                // Non-contiguous: finalize current block and start new one
-                blocks.push(current_block);
-                current_block = BulkWriteBlock {
-                    start_address: addr,
-                    data,
-                };
-            }
-        }
+impl<T, const ADDR: u16, const BASE: u16> ReadOnly<T, ADDR, 4, BASE> {
+    /// Read command, usable in `const` and `static` items
+    pub const READ_COMMAND: [u8; 6] = read_command_array(BASE + ADDR);
+}
 
-        blocks.push(current_block);
-        blocks
+impl<T, const ADDR: u16, const BASE: u16> WriteOnly<T, ADDR, 4, BASE> {
+    /// Builds the write command for the raw register value `bits`, masked by
+    /// [`WriteMask`] like the runtime commands, at compile time.
+    pub const fn write_command_array(bits: u32) -> [u8; 6]
+    where
+        T: WriteMask,
+    {
+        let bits = masked_bits::<T>(bits as u64) as u32;
+        write_command_array(BASE + ADDR, &bits.to_le_bytes())
     }
+}
 
-    /// Returns the contiguous blocks that would be written.
-    /// For debugging mainly
-    pub fn get_blocks(&self) -> Vec<BulkWriteBlock> {
-        self.compute_blocks()
-    }
+impl<T, const ADDR: u16, const BASE: u16> ReadWrite<T, ADDR, 4, BASE> {
+    /// Read command, usable in `const` and `static` items
+    pub const READ_COMMAND: [u8; 6] = read_command_array(BASE + ADDR);
 
-    pub fn len(&self) -> usize {
-        self.registers.len()
+    /// Builds the write command for the raw register value `bits`, masked by
+    /// [`WriteMask`] like the runtime commands, at compile time.
+    pub const fn write_command_array(bits: u32) -> [u8; 6]
+    where
+        T: WriteMask,
+    {
+        let bits = masked_bits::<T>(bits as u64) as u32;
+        write_command_array(BASE + ADDR, &bits.to_le_bytes())
     }
+}
 
-    pub fn is_empty(&self) -> bool {
-        self.registers.is_empty()
+impl<T, const ADDR: u16, const BASE: u16> ReadOnly<T, ADDR, 8, BASE> {
+    /// Read command, usable in `const` and `static` items
+    pub const READ_COMMAND: [u8; 10] = read_command_array(BASE + ADDR);
+}
+
+impl<T, const ADDR: u16, const BASE: u16> WriteOnly<T, ADDR, 8, BASE> {
+    /// Builds the write command for the raw register value `bits`, masked by
+    /// [`WriteMask`] like the runtime commands, at compile time.
+    pub const fn write_command_array(bits: u64) -> [u8; 10]
+    where
+        T: WriteMask,
+    {
+        let bits = masked_bits::<T>(bits);
+        write_command_array(BASE + ADDR, &bits.to_le_bytes())
     }
 }
 
-impl<'a> Default for BulkWrites<'a> {
-    fn default() -> Self {
-        Self::new()
+impl<T, const ADDR: u16, const BASE: u16> ReadWrite<T, ADDR, 8, BASE> {
+    /// Read command, usable in `const` and `static` items
+    pub const READ_COMMAND: [u8; 10] = read_command_array(BASE + ADDR);
+
+    /// Builds the write command for the raw register value `bits`, masked by
+    /// [`WriteMask`] like the runtime commands, at compile time.
+    pub const fn write_command_array(bits: u64) -> [u8; 10]
+    where
+        T: WriteMask,
+    {
+        let bits = masked_bits::<T>(bits);
+        write_command_array(BASE + ADDR, &bits.to_le_bytes())
     }
 }
 
 // =============================================================================
-// Bulk Register Read
+// Bulk Register Storage
 // =============================================================================
 
-struct BulkReadBlock {
-    start_address: u16,
-    total_size: usize,
+/// Registers of a bulk operation, kept sorted by address
+#[cfg(feature = "alloc")]
+type RegisterList<T> = Vec<T>;
+
+#[cfg(not(feature = "alloc"))]
+type RegisterList<T> = heapless::Vec<T, MAX_BULK_REGISTERS>;
 
-    /// (offset_in_block, original_register_index, size)
-    reg_infos: Vec<(usize, usize, usize)>,
+#[cfg(feature = "alloc")]
+fn insert_register<T>(
+    list: &mut RegisterList<T>,
+    index: usize,
+    reg: T,
+) -> Result<(), CapacityError> {
+    list.insert(index, reg);
+    Ok(())
 }
 
-pub struct BulkReads<'a> {
-    registers: Vec<&'a mut dyn Readable>,
+#[cfg(not(feature = "alloc"))]
+fn insert_register<T>(
+    list: &mut RegisterList<T>,
+    index: usize,
+    reg: T,
+) -> Result<(), CapacityError> {
+    list.insert(index, reg).map_err(|_| CapacityError)
 }
 
-impl<'a> BulkReads<'a> {
+/// Checks a maximum transfer length holds a command for any register.
+///
+/// # Panics
+/// Panics if `len` is shorter than [`MAX_COMMAND_LEN`].
+const fn check_max_transfer_len(len: usize) -> usize {
+    assert!(
+        len >= MAX_COMMAND_LEN,
+        "maximum transfer length shorter than a register command"
+    );
+    len
+}
+
+/// A run of registers at contiguous addresses, as a range of the sorted register list
+#[derive(Debug, Clone, Copy)]
+struct Span {
+    start_address: u16,
+    size: usize,
+    first: usize,
+    end: usize,
+}
+
+impl Span {
+    /// Offset of `address` in the span data
+    fn offset(&self, address: u16) -> usize {
+        (address - self.start_address) as usize
+    }
+}
+
+/// Finds the contiguous span starting at register `first`.
+///
+/// `layout` returns the (address, size) of the register at an index, and
+/// `split` whether a span must end before it even if contiguous. A span holds
+/// at most `max_size` data bytes, unless its first register is larger.
+///
+/// Registers overlapping the span extend it, so duplicates share its bytes,
+/// and a register entirely within the span is always part of it.
+fn next_span(
+    count: usize,
+    first: usize,
+    max_size: usize,
+    layout: impl Fn(usize) -> (u16, usize),
+    split: impl Fn(usize) -> bool,
+) -> Option<Span> {
+    if first >= count {
+        return None;
+    }
+
+    let (start_address, mut size) = layout(first);
+    let mut end = first + 1;
+    while end < count {
+        let (addr, reg_size) = layout(end);
+        let reg_end = (addr - start_address) as usize + reg_size;
+        if reg_end > size {
+            if addr > start_address + size as u16 || reg_end > max_size || split(end) {
+                break;
+            }
+            size = reg_end;
+        }
+        end += 1;
+    }
+
+    Some(Span {
+        start_address,
+        size,
+        first,
+        end,
+    })
+}
+
+// =============================================================================
+// Bulk Register Write
+// =============================================================================
+
+/// Whether `a` and `b` overlap and write different data to a shared byte
+fn conflicts(a: &dyn Writable, b: &dyn Writable) -> bool {
+    let start = a.address().max(b.address());
+    let end = (a.address() as usize + a.size()).min(b.address() as usize + b.size());
+    if start as usize >= end {
+        return false;
+    }
+
+    let mut a_bytes = [0u8; MAX_REGISTER_SIZE];
+    let mut b_bytes = [0u8; MAX_REGISTER_SIZE];
+    a.write_value(&mut a_bytes[..a.size()]);
+    b.write_value(&mut b_bytes[..b.size()]);
+
+    let len = end - start as usize;
+    let a_offset = (start - a.address()) as usize;
+    let b_offset = (start - b.address()) as usize;
+    a_bytes[a_offset..a_offset + len] != b_bytes[b_offset..b_offset + len]
+}
+
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+pub struct BulkWriteBlock {
+    pub start_address: u16,
+
+    pub data: Vec<u8>,
+}
+
+/// Registers written together, coalesced into one command per contiguous run
+///
+/// Registers are written by [`WriteStage`]: a run ending in a commit or command
+/// register is sent after the configuration runs, so RFn_CNM applies the
+/// channel written before it and RFn_CMD comes last. Registers whose write
+/// triggers an action are sent in a command of their own, unless
+/// [`coalesce_side_effects`](Self::coalesce_side_effects) allows otherwise.
+/// Runs longer than the maximum transfer length are split into several
+/// commands, each addressed at its first register.
+pub struct BulkWrites<'a> {
+    registers: RegisterList<&'a mut dyn Writable>,
+    coalesce_side_effects: bool,
+    max_transfer_len: usize,
+}
+
+impl<'a> BulkWrites<'a> {
     pub fn new() -> Self {
         Self {
-            registers: Vec::new(),
+            registers: RegisterList::new(),
+            coalesce_side_effects: false,
+            max_transfer_len: usize::MAX,
         }
     }
 
-    pub fn add<R: Readable>(&mut self, reg: &'a mut R) {
-        self.registers.push(reg);
+    /// Limits every command, header included, to `len` bytes.
+    ///
+    /// # Panics
+    /// Panics if `len` is shorter than [`MAX_COMMAND_LEN`].
+    pub fn set_max_transfer_len(&mut self, len: usize) {
+        self.max_transfer_len = check_max_transfer_len(len);
     }
 
-    /// Generates read commands for contiguous registers in the collection.
+    /// Lets registers whose write triggers an action share a command with
+    /// their neighbours.
+    pub fn coalesce_side_effects(&mut self, coalesce: bool) {
+        self.coalesce_side_effects = coalesce;
+    }
+
+    fn isolated(&self, index: usize) -> bool {
+        !self.coalesce_side_effects && self.registers[index].triggers()
+    }
+
+    /// Adds a register to the collection.
     ///
-    /// This method analyzes the registers and groups contiguous addresses together
-    /// into block read commands. Each returned `Vec<u8>` is a complete SPI command
-    /// that should be sent in a single transaction.
+    /// A register overlapping one already added is written once, in the same
+    /// command, provided both write the same data to the shared bytes.
+    ///
+    /// # Panics
+    /// Panics if the register overlaps one already added with different data,
+    /// see [`WriteError::Conflict`], and without `alloc` if more than
+    /// `MAX_BULK_REGISTERS` registers are added. Use [`try_add`](Self::try_add)
+    /// to handle either case.
+    pub fn add<W: Writable>(&mut self, reg: &'a mut W) {
+        self.try_add(reg).expect("BulkWrites register rejected");
+    }
+
+    /// Adds a register to the collection, failing if it is full or the
+    /// register conflicts with one already added.
+    pub fn try_add<W: Writable>(&mut self, reg: &'a mut W) -> Result<(), WriteError> {
+        self.try_add_dyn(reg)
+    }
+
+    /// Adds a register trait object to the collection, failing if it is full
+    /// or the register conflicts with one already added.
+    pub fn try_add_dyn(&mut self, reg: &'a mut dyn Writable) -> Result<(), WriteError> {
+        if let Some(existing) = self.registers.iter().find(|r| conflicts(&***r, &*reg)) {
+            return Err(WriteError::Conflict {
+                address: reg.address(),
+                existing: existing.address(),
+            });
+        }
+
+        let addr = reg.address();
+        let index = self.registers.partition_point(|r| r.address() <= addr);
+        Ok(insert_register(&mut self.registers, index, reg)?)
+    }
+
+    /// Records every register as written, after the writes were executed.
+    pub fn mark_clean(&mut self) {
+        for reg in self.registers.iter_mut() {
+            reg.mark_clean();
+        }
+    }
+
+    /// Contiguous span starting at `first`, ending before a register of an
+    /// earlier stage so a span never writes a register after a later stage one.
+    fn span(&self, first: usize, max_len: usize) -> Option<Span> {
+        next_span(
+            self.registers.len(),
+            first,
+            max_len.min(self.max_transfer_len) - HEADER_LEN,
+            |i| (self.registers[i].address(), self.registers[i].size()),
+            |i| {
+                self.registers[i].write_stage() < self.registers[i - 1].write_stage()
+                    || self.isolated(i)
+                    || self.isolated(i - 1)
+            },
+        )
+    }
+
+    /// Spans in write order, by the stage of their last register, then by
+    /// address, in commands of at most `max_len` bytes
+    fn spans(&self, max_len: usize) -> impl Iterator<Item = Span> + '_ {
+        WriteStage::ALL.into_iter().flat_map(move |stage| {
+            iter::successors(self.span(0, max_len), move |span| {
+                self.span(span.end, max_len)
+            })
+            .filter(move |span| self.registers[span.end - 1].write_stage() == stage)
+        })
+    }
+
+    /// Generates write commands for contiguous registers in the collection.
     ///
     /// # Returns
     /// A vector of SPI commands. Each command consists of a 2-byte header
-    /// followed by zero bytes (placeholders for the response data).
+    /// followed by data bytes for contiguous registers.
+    #[cfg(feature = "alloc")]
     pub fn generate_commands(&self) -> Vec<Vec<u8>> {
         let blocks = self.compute_blocks();
 
         blocks
             .into_iter()
             .map(|block| {
-                build_read_command(generate_read_header(block.start_address), block.total_size)
+                build_write_command(generate_write_header(block.start_address), block.data)
             })
             .collect()
     }
 
-    /// Computes the contiguous blocks for reading.
-    fn compute_blocks(&self) -> Vec<BulkReadBlock> {
-        if self.registers.is_empty() {
-            return Vec::new();
+    /// Builds each write command in `buf` and passes it to `send`.
+    ///
+    /// `buf` must hold at least [`max_command_len`](Self::max_command_len) bytes.
+    pub fn execute<E: From<BufferTooSmall>>(
+        &self,
+        buf: &mut [u8],
+        send: impl FnMut(&[u8]) -> Result<(), E>,
+    ) -> Result<(), E> {
+        self.execute_within(usize::MAX, buf, send)
+    }
+
+    /// Like [`execute`](Self::execute), also splitting commands at `max_len` bytes.
+    pub(crate) fn execute_within<E: From<BufferTooSmall>>(
+        &self,
+        max_len: usize,
+        buf: &mut [u8],
+        mut send: impl FnMut(&[u8]) -> Result<(), E>,
+    ) -> Result<(), E> {
+        for span in self.spans(max_len) {
+            send(self.build_command(span, buf)?)?;
         }
+        Ok(())
+    }
 
-        // Create sortable info with original indices
-        // The orignal indices will be used when filling the registers.
-        let mut reg_infos: Vec<_> = self
-            .registers
-            .iter()
-            .enumerate()
-            .map(|(idx, reg)| (reg.address(), reg.size(), idx))
-            .collect();
+    /// Async version of [`execute`](Self::execute).
+    #[cfg(feature = "async")]
+    pub async fn execute_async<E: From<BufferTooSmall>>(
+        &self,
+        buf: &mut [u8],
+        send: impl AsyncFnMut(&[u8]) -> Result<(), E>,
+    ) -> Result<(), E> {
+        self.execute_within_async(usize::MAX, buf, send).await
+    }
+
+    /// Async version of [`execute_within`](Self::execute_within).
+    #[cfg(feature = "async")]
+    pub(crate) async fn execute_within_async<E: From<BufferTooSmall>>(
+        &self,
+        max_len: usize,
+        buf: &mut [u8],
+        mut send: impl AsyncFnMut(&[u8]) -> Result<(), E>,
+    ) -> Result<(), E> {
+        for span in self.spans(max_len) {
+            send(self.build_command(span, buf)?).await?;
+        }
+        Ok(())
+    }
+
+    fn build_command<'b>(
+        &self,
+        span: Span,
+        buf: &'b mut [u8],
+    ) -> Result<&'b mut [u8], BufferTooSmall> {
+        let cmd = command_slice(buf, HEADER_LEN + span.size)?;
+        cmd[..HEADER_LEN].copy_from_slice(&generate_write_header(span.start_address));
+
+        for reg in &self.registers[span.first..span.end] {
+            let offset = HEADER_LEN + span.offset(reg.address());
+            reg.write_value(&mut cmd[offset..offset + reg.size()]);
+        }
+        Ok(cmd)
+    }
 
-        // Sort by address
-        reg_infos.sort_by_key(|(addr, _, _)| *addr);
+    /// Length of the longest command, the buffer size needed by [`execute`](Self::execute).
+    ///
+    /// Never more than the maximum transfer length.
+    pub fn max_command_len(&self) -> usize {
+        self.max_command_len_within(self.max_transfer_len)
+    }
+
+    /// Like [`max_command_len`](Self::max_command_len), for commands split at `max_len` bytes.
+    pub(crate) fn max_command_len_within(&self, max_len: usize) -> usize {
+        self.spans(max_len)
+            .map(|span| HEADER_LEN + span.size)
+            .max()
+            .unwrap_or(0)
+    }
 
+    /// Computes the contiguous blocks for writing.
+    #[cfg(feature = "alloc")]
+    fn compute_blocks(&self) -> Vec<BulkWriteBlock> {
         let mut blocks = Vec::new();
-        let (first_addr, first_size, first_idx) = reg_infos[0];
-        let mut current_block = BulkReadBlock {
-            start_address: first_addr,
-            total_size: first_size,
-            reg_infos: vec![(0, first_idx, first_size)], // (offset, original_index, size)
-        };
+        for span in self.spans(usize::MAX) {
+            let mut data = vec![0x00; span.size];
+            for reg in &self.registers[span.first..span.end] {
+                let offset = span.offset(reg.address());
+                reg.write_value(&mut data[offset..offset + reg.size()]);
+            }
 
-        for (addr, size, idx) in reg_infos.into_iter().skip(1) {
-            let expected_next_addr = current_block.start_address + current_block.total_size as u16;
-
-            if addr == expected_next_addr {
-                // Contiguous: add to current block
-                let offset = current_block.total_size;
-                current_block.total_size += size;
-                current_block.reg_infos.push((offset, idx, size));
-            } else {
-                // Non-contiguous: finalize current block and start new one
-                blocks.push(current_block);
-                current_block = BulkReadBlock {
-                    start_address: addr,
-                    total_size: size,
-                    reg_infos: vec![(0, idx, size)],
-                };
+            blocks.push(BulkWriteBlock {
+                start_address: span.start_address,
+                data,
+            });
+        }
+        blocks
+    }
+
+    /// Returns the contiguous blocks that would be written.
+    /// For debugging mainly
+    #[cfg(feature = "alloc")]
+    pub fn get_blocks(&self) -> Vec<BulkWriteBlock> {
+        self.compute_blocks()
+    }
+
+    pub fn len(&self) -> usize {
+        self.registers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.registers.is_empty()
+    }
+}
+
+impl<'a> Default for BulkWrites<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// =============================================================================
+// Bulk Register Read
+// =============================================================================
+
+/// Registers read together, coalesced into one command per contiguous run
+///
+/// Registers cleared by a read are read in a command of their own, unless
+/// [`coalesce_side_effects`](Self::coalesce_side_effects) allows otherwise.
+/// Runs longer than the maximum transfer length are split into several
+/// commands, and each response is decoded into the registers it covers.
+pub struct BulkReads<'a> {
+    registers: RegisterList<&'a mut dyn Readable>,
+    coalesce_side_effects: bool,
+    max_transfer_len: usize,
+}
+
+impl<'a> BulkReads<'a> {
+    pub fn new() -> Self {
+        Self {
+            registers: RegisterList::new(),
+            coalesce_side_effects: false,
+            max_transfer_len: usize::MAX,
+        }
+    }
+
+    /// Limits every command, header included, to `len` bytes.
+    ///
+    /// # Panics
+    /// Panics if `len` is shorter than [`MAX_COMMAND_LEN`].
+    pub fn set_max_transfer_len(&mut self, len: usize) {
+        self.max_transfer_len = check_max_transfer_len(len);
+    }
+
+    /// Lets registers cleared by a read share a command with their neighbours.
+    pub fn coalesce_side_effects(&mut self, coalesce: bool) {
+        self.coalesce_side_effects = coalesce;
+    }
+
+    fn isolated(&self, index: usize) -> bool {
+        !self.coalesce_side_effects && self.registers[index].clears_on_read()
+    }
+
+    /// Adds a register to the collection.
+    ///
+    /// Registers overlapping others are read once, each decoding its own bytes
+    /// from the shared response.
+    ///
+    /// # Panics
+    /// Without `alloc`, panics if more than `MAX_BULK_REGISTERS` registers are added.
+    pub fn add<R: Readable>(&mut self, reg: &'a mut R) {
+        self.try_add(reg).expect("BulkReads capacity exceeded");
+    }
+
+    /// Adds a register to the collection, failing if it is full.
+    pub fn try_add<R: Readable>(&mut self, reg: &'a mut R) -> Result<(), CapacityError> {
+        self.try_add_dyn(reg)
+    }
+
+    /// Adds a register trait object to the collection, failing if it is full.
+    pub fn try_add_dyn(&mut self, reg: &'a mut dyn Readable) -> Result<(), CapacityError> {
+        let addr = reg.address();
+        let index = self.registers.partition_point(|r| r.address() <= addr);
+        insert_register(&mut self.registers, index, reg)
+    }
+
+    fn span(&self, first: usize, max_len: usize) -> Option<Span> {
+        next_span(
+            self.registers.len(),
+            first,
+            max_len.min(self.max_transfer_len) - HEADER_LEN,
+            |i| (self.registers[i].address(), self.registers[i].size()),
+            |i| self.isolated(i) || self.isolated(i - 1),
+        )
+    }
+
+    /// Decodes the data of one block response into its registers.
+    fn fill_span(&mut self, span: Span, data: &[u8]) {
+        for reg in &mut self.registers[span.first..span.end] {
+            let offset = span.offset(reg.address());
+            if let Some(bytes) = data.get(offset..offset + reg.size()) {
+                reg.set_from_bytes(bytes);
             }
         }
+    }
 
-        blocks.push(current_block);
-        blocks
+    /// Generates read commands for contiguous registers in the collection.
+    ///
+    /// This method analyzes the registers and groups contiguous addresses together
+    /// into block read commands. Each returned `Vec<u8>` is a complete SPI command
+    /// that should be sent in a single transaction.
+    ///
+    /// # Returns
+    /// A vector of SPI commands. Each command consists of a 2-byte header
+    /// followed by zero bytes (placeholders for the response data).
+    #[cfg(feature = "alloc")]
+    pub fn generate_commands(&self) -> Vec<Vec<u8>> {
+        self.get_block_info()
+            .into_iter()
+            .map(|(start_address, total_size)| {
+                build_read_command(generate_read_header(start_address), total_size)
+            })
+            .collect()
+    }
+
+    /// Builds each read command in `buf`, passes it to `transfer` to be
+    /// exchanged in place, then decodes the response into the registers.
+    ///
+    /// `buf` must hold at least [`max_command_len`](Self::max_command_len) bytes.
+    pub fn execute<E: From<BufferTooSmall>>(
+        &mut self,
+        buf: &mut [u8],
+        transfer: impl FnMut(&mut [u8]) -> Result<(), E>,
+    ) -> Result<(), E> {
+        self.execute_within(usize::MAX, buf, transfer)
+    }
+
+    /// Like [`execute`](Self::execute), also splitting commands at `max_len` bytes.
+    pub(crate) fn execute_within<E: From<BufferTooSmall>>(
+        &mut self,
+        max_len: usize,
+        buf: &mut [u8],
+        mut transfer: impl FnMut(&mut [u8]) -> Result<(), E>,
+    ) -> Result<(), E> {
+        let mut first = 0;
+        while let Some(span) = self.span(first, max_len) {
+            let cmd = Self::build_command(span, buf)?;
+            transfer(cmd)?;
+            self.fill_span(span, &cmd[HEADER_LEN..]);
+            first = span.end;
+        }
+        Ok(())
+    }
+
+    /// Async version of [`execute`](Self::execute).
+    #[cfg(feature = "async")]
+    pub async fn execute_async<E: From<BufferTooSmall>>(
+        &mut self,
+        buf: &mut [u8],
+        transfer: impl AsyncFnMut(&mut [u8]) -> Result<(), E>,
+    ) -> Result<(), E> {
+        self.execute_within_async(usize::MAX, buf, transfer).await
+    }
+
+    /// Async version of [`execute_within`](Self::execute_within).
+    #[cfg(feature = "async")]
+    pub(crate) async fn execute_within_async<E: From<BufferTooSmall>>(
+        &mut self,
+        max_len: usize,
+        buf: &mut [u8],
+        mut transfer: impl AsyncFnMut(&mut [u8]) -> Result<(), E>,
+    ) -> Result<(), E> {
+        let mut first = 0;
+        while let Some(span) = self.span(first, max_len) {
+            let cmd = Self::build_command(span, buf)?;
+            transfer(&mut *cmd).await?;
+            self.fill_span(span, &cmd[HEADER_LEN..]);
+            first = span.end;
+        }
+        Ok(())
+    }
+
+    fn build_command(span: Span, buf: &mut [u8]) -> Result<&mut [u8], BufferTooSmall> {
+        let cmd = command_slice(buf, HEADER_LEN + span.size)?;
+        cmd[..HEADER_LEN].copy_from_slice(&generate_read_header(span.start_address));
+        cmd[HEADER_LEN..].fill(0x00);
+        Ok(cmd)
+    }
+
+    /// Length of the longest command, the buffer size needed by [`execute`](Self::execute).
+    ///
+    /// Never more than the maximum transfer length.
+    pub fn max_command_len(&self) -> usize {
+        self.max_command_len_within(self.max_transfer_len)
+    }
+
+    /// Like [`max_command_len`](Self::max_command_len), for commands split at `max_len` bytes.
+    pub(crate) fn max_command_len_within(&self, max_len: usize) -> usize {
+        let mut max = 0;
+        let mut first = 0;
+        while let Some(span) = self.span(first, max_len) {
+            max = max.max(HEADER_LEN + span.size);
+            first = span.end;
+        }
+        max
     }
 
     /// Returns information about the contiguous blocks that would be read.
     /// For debugging
+    #[cfg(feature = "alloc")]
     pub fn get_block_info(&self) -> Vec<(u16, usize)> {
-        self.compute_blocks()
-            .into_iter()
-            .map(|b| (b.start_address, b.total_size))
-            .collect()
+        let mut blocks = Vec::new();
+        let mut first = 0;
+        while let Some(span) = self.span(first, usize::MAX) {
+            blocks.push((span.start_address, span.size));
+            first = span.end;
+        }
+        blocks
     }
 
-    /// Parses response data from SPI transactions and updates all stored registers.
+    /// Parses response data from SPI transactions and updates the stored registers.
     ///
     /// The responses should be in the same order as the commands returned by
     /// `generate_commands()`. Each response should include the 2-byte header
     /// followed by the actual data bytes.
     ///
-    /// # Arguments
-    /// * `responses` - A slice of SPI response buffers, one for each command
-    pub fn parse_responses(&mut self, responses: &[Vec<u8>]) {
-        let blocks = self.compute_blocks();
-
-        // TODO verify the format of SPI responses from spidev
-        // Might not need the header at all.
-        for (block, response) in blocks.iter().zip(responses.iter()) {
-            // Skip the 2-byte header in the response
-            let data = if response.len() > 2 {
-                &response[2..]
-            } else {
-                continue;
+    /// Registers covered by a complete response are updated even if others are
+    /// not. The error lists the registers left stale, and is also returned if
+    /// there are more responses than commands.
+    #[cfg(feature = "alloc")]
+    pub fn parse_responses(&mut self, responses: &[Vec<u8>]) -> Result<(), ResponseError> {
+        let error = self.check_responses(responses);
+        let mut first = 0;
+        for response in responses {
+            let Some(span) = self.span(first, usize::MAX) else {
+                break;
             };
+            first = span.end;
 
-            for (offset, reg_idx, size) in &block.reg_infos {
-                if offset + size <= data.len() {
-                    let reg_data = &data[*offset..*offset + *size];
-                    // Update the register at the original index
-                    if let Some(reg) = self.registers.get_mut(*reg_idx) {
-                        reg.set_from_bytes(reg_data);
-                    }
-                }
+            // Full-duplex transfers return as many bytes as were sent, so the
+            // response still starts with the 2 bytes clocked in during the header.
+            self.fill_span(span, response.get(HEADER_LEN..).unwrap_or_default());
+        }
+        error.map_or(Ok(()), Err)
+    }
+
+    /// Like [`parse_responses`](Self::parse_responses), but fails the whole
+    /// batch: no register is updated unless there is exactly one complete
+    /// response per command.
+    ///
+    /// On error every register is listed as stale, the ones not covered with
+    /// their own reason and the others as [`StaleReason::BatchFailed`].
+    #[cfg(feature = "alloc")]
+    pub fn parse_responses_strict(&mut self, responses: &[Vec<u8>]) -> Result<(), ResponseError> {
+        let Some(mut error) = self.check_responses(responses) else {
+            return self.parse_responses(responses);
+        };
+
+        // Nothing was updated, so every register is stale
+        let failed = core::mem::take(&mut error.stale);
+        error.stale = self
+            .registers
+            .iter()
+            .map(|reg| {
+                let address = reg.address();
+                let reason = failed
+                    .iter()
+                    .find(|stale| stale.address == address)
+                    .map_or(StaleReason::BatchFailed, |stale| stale.reason);
+                StaleRegister { address, reason }
+            })
+            .collect();
+        Err(error)
+    }
+
+    /// Returns the registers `responses` do not cover, if any, or a response
+    /// count mismatch.
+    #[cfg(feature = "alloc")]
+    fn check_responses(&self, responses: &[Vec<u8>]) -> Option<ResponseError> {
+        let mut stale = Vec::new();
+        let mut expected = 0;
+        let mut first = 0;
+        while let Some(span) = self.span(first, usize::MAX) {
+            let response = responses.get(expected);
+            for reg in &self.registers[span.first..span.end] {
+                let end = HEADER_LEN + span.offset(reg.address()) + reg.size();
+                let reason = match response {
+                    None => StaleReason::MissingResponse,
+                    Some(response) if response.len() < end => StaleReason::ShortResponse {
+                        len: response.len(),
+                        expected: HEADER_LEN + span.size,
+                    },
+                    Some(_) => continue,
+                };
+                stale.push(StaleRegister {
+                    address: reg.address(),
+                    reason,
+                });
             }
+            expected += 1;
+            first = span.end;
         }
+
+        (expected != responses.len() || !stale.is_empty()).then_some(ResponseError {
+            expected,
+            received: responses.len(),
+            stale,
+        })
     }
 
     pub fn len(&self) -> usize {
@@ -607,7 +1760,7 @@ pub struct RfPn {
     /// - 0x34: AT86RF215
     /// - 0x35: AT86RF215IQ
     /// - 0x36: AT86RF215M
-    #[bits(8, access = RO, from = DevicePartNumber::from_bits)]
+    #[bits(8, access = RO, default = 0, from = DevicePartNumber::from_bits)]
     pub pn: DevicePartNumber,
 }
 
@@ -647,8 +1800,8 @@ pub struct RfCfg {
     /// - 1: 4mA
     /// - 2: 6mA
     /// - 3: 8mA
-    #[bits(2)]
-    pub drv: u8,
+    #[bits(2, from = DriverStrength::from_bits)]
+    pub drv: DriverStrength,
 
     /// IRQ Polarity
     /// - 0: Active high
@@ -680,16 +1833,16 @@ pub struct RfClko {
     /// - 5: 4MHz
     /// - 6: 2MHz
     /// - 7: 1MHz
-    #[bits(3)]
-    pub os: u8,
+    #[bits(3, from = ClockOutput::from_bits)]
+    pub os: ClockOutput,
 
     /// Output Driver Strength CLKO
     /// - 0: 2mA
     /// - 1: 4mA
     /// - 2: 6mA
     /// - 3: 8mA
-    #[bits(2)]
-    pub drv: u8,
+    #[bits(2, from = DriverStrength::from_bits)]
+    pub drv: DriverStrength,
 
     #[bits(3)]
     __: u8,
@@ -961,7 +2114,7 @@ pub struct RfnState {
     /// - 5: RX (Receive)
     /// - 6: TRANSITION (State transition in progress)
     /// - 7: RESET (Transceiver in RESET or SLEEP)
-    #[bits(3, access = RO, from = TransceiverState::from_bits)]
+    #[bits(3, access = RO, default = 0, from = TransceiverState::from_bits)]
     pub state: TransceiverState,
 
     #[bits(5)]
@@ -1009,25 +2162,35 @@ pub struct RfnCcf0 {
     pub ccf0: u16,
 }
 
-/// RFn_CN - Channel Number and Mode
+/// RFn_CNL - Channel Number Low Byte
 ///
-/// 16-bit channel number (9-bit) and mode configuration.
-#[bitfield(u16)]
-pub struct RfnCn {
-    /// Channel Number (9-bit: 0-511)
-    #[bits(9)]
-    pub cn: u16,
+/// Lower 8 bits of the 9-bit channel number. Only applied once RFn_CNM is written.
+#[bitfield(u8)]
+pub struct RfnCnl {
+    /// Channel Number bits 7:0
+    #[bits(8)]
+    pub cnl: u8,
+}
+
+/// RFn_CNM - Channel Mode and Channel Number High Bit
+///
+/// Writing this register applies CS, CCF0, CNL and CNM, so it must be written last.
+#[bitfield(u8)]
+pub struct RfnCnm {
+    /// Channel Number bit 8
+    #[bits(1)]
+    pub cnh: bool,
 
     #[bits(5)]
-    __: u16,
+    __: u8,
 
     /// Channel Setting Mode
     /// - 0: IEEE compliant (CCF0+CN*CS)*25kHz + offset
     /// - 1: Fine resolution 389.5-510MHz with ~99Hz stepping
     /// - 2: Fine resolution 779-1020MHz with ~198Hz stepping
     /// - 3: Fine resolution 2400-2483.5MHz with ~397Hz stepping
-    #[bits(2)]
-    pub cm: u8,
+    #[bits(2, from = ChannelMode::from_bits)]
+    pub cm: ChannelMode,
 }
 
 /// RFn_RXBWC - Receiver Filter Bandwidth Control
@@ -1048,8 +2211,8 @@ pub struct RfnRxbwc {
     /// - 9: 1250kHz @ 2000kHz IF
     /// - 10: 1600kHz @ 2000kHz IF
     /// - 11: 2000kHz @ 2000kHz IF
-    #[bits(4)]
-    pub bw: u8,
+    #[bits(4, from = ReceiverBandwidth::from_bits)]
+    pub bw: ReceiverBandwidth,
 
     /// IF Shift - Multiplies IF by 1.25
     /// - 0: Normal IF
@@ -1081,8 +2244,8 @@ pub struct RfnRxdfe {
     /// - 6: 2000/3 kHz
     /// - 8: 500kHz
     /// - 10: 400kHz
-    #[bits(4)]
-    pub sr: u8,
+    #[bits(4, from = SampleRate::from_bits)]
+    pub sr: SampleRate,
 
     #[bits(1)]
     __: u8,
@@ -1093,8 +2256,8 @@ pub struct RfnRxdfe {
     /// - 2: 0.5 × fS/2
     /// - 3: 0.75 × fS/2
     /// - 4: 1.0 × fS/2 (bypass)
-    #[bits(3)]
-    pub rcut: u8,
+    #[bits(3, from = RelativeCutoff::from_bits)]
+    pub rcut: RelativeCutoff,
 }
 
 /// RFn_AGCC - AGC Control
@@ -1206,8 +2369,8 @@ pub struct RfnEdd {
     /// - 1: 8µs
     /// - 2: 32µs
     /// - 3: 128µs
-    #[bits(2)]
-    pub dtb: u8,
+    #[bits(2, from = EdDurationBasis::from_bits)]
+    pub dtb: EdDurationBasis,
 
     /// Duration Factor
     /// Averaging time = DF × DTB
@@ -1231,7 +2394,7 @@ pub struct RfnEdv {
 /// PLL configuration and status.
 #[bitfield(u8)]
 pub struct RfnPll {
-    #[bits(1)]
+    #[bits(1, default = 1)]
     __: u8,
 
     /// PLL Lock Status (Read Only)
@@ -1240,7 +2403,7 @@ pub struct RfnPll {
     #[bits(1, access = RO)]
     pub ls: bool,
 
-    #[bits(2)]
+    #[bits(2, default = 0b10)]
     __: u8,
 
     /// Loop Bandwidth (Sub-1GHz only)
@@ -1285,8 +2448,8 @@ pub struct RfnTxcutc {
     /// - 9: 625kHz
     /// - 10: 800kHz
     /// - 11: 1000kHz
-    #[bits(4)]
-    pub lpfcut: u8,
+    #[bits(4, from = TxCutoff::from_bits)]
+    pub lpfcut: TxCutoff,
 
     #[bits(2)]
     __: u8,
@@ -1296,8 +2459,8 @@ pub struct RfnTxcutc {
     /// - 1: 8µs
     /// - 2: 16µs
     /// - 3: 32µs
-    #[bits(2)]
-    pub paramp: u8,
+    #[bits(2, from = PaRampTime::from_bits)]
+    pub paramp: PaRampTime,
 }
 
 /// RFn_TXDFE - Transmitter Digital Frontend
@@ -1314,8 +2477,8 @@ pub struct RfnTxdfe {
     /// - 6: 2000/3 kHz
     /// - 8: 500kHz
     /// - 10: 400kHz
-    #[bits(4)]
-    pub sr: u8,
+    #[bits(4, from = SampleRate::from_bits)]
+    pub sr: SampleRate,
 
     /// Direct Modulation
     /// - 0: Direct modulation disabled
@@ -1329,8 +2492,8 @@ pub struct RfnTxdfe {
     /// - 2: 0.5 × fS/2
     /// - 3: 0.75 × fS/2
     /// - 4: 1.0 × fS/2 (bypass)
-    #[bits(3)]
-    pub rcut: u8,
+    #[bits(3, from = RelativeCutoff::from_bits)]
+    pub rcut: RelativeCutoff,
 }
 
 /// RFn_PAC - Power Amplifier Control
@@ -1444,12 +2607,12 @@ pub struct RfnTxdacq {
 #[bitfield(u8)]
 pub struct BbcnPc {
     /// PHY Type
-    /// - 0: FSK
-    /// - 1: OFDM
-    /// - 2: OQPSK
-    /// - 3: Reserved/Legacy O-QPSK
-    #[bits(2)]
-    pub pt: u8,
+    /// - 0: PHY off
+    /// - 1: MR-FSK
+    /// - 2: MR-OFDM
+    /// - 3: MR-O-QPSK
+    #[bits(2, from = PhyType::from_bits)]
+    pub pt: PhyType,
 
     /// Baseband Enable
     /// - 0: Baseband disabled
@@ -1624,8 +2787,8 @@ pub struct BbcnOfdmc {
     /// - 1: Option 2 (BPSK, QPSK, 16-QAM)
     /// - 2: Option 3 (QPSK, 16-QAM)
     /// - 3: Option 4 (BPSK)
-    #[bits(2)]
-    pub opt: u8,
+    #[bits(2, from = OfdmOption::from_bits)]
+    pub opt: OfdmOption,
 
     /// Pilot Interleaving
     /// - 0: Disabled
@@ -1674,8 +2837,8 @@ pub struct BbcnOqpskc0 {
     /// - 1: 200kchip/s
     /// - 2: 1000kchip/s
     /// - 3: 2000kchip/s
-    #[bits(2)]
-    pub fchip: u8,
+    #[bits(2, from = OqpskChipFrequency::from_bits)]
+    pub fchip: OqpskChipFrequency,
 
     #[bits(1)]
     __: u8,
@@ -2070,8 +3233,14 @@ pub struct BbcnFskc0 {
 #[bitfield(u8)]
 pub struct BbcnFskc1 {
     /// Symbol Rate
-    #[bits(4)]
-    pub srate: u8,
+    /// - 0: 50kHz
+    /// - 1: 100kHz
+    /// - 2: 150kHz
+    /// - 3: 200kHz
+    /// - 4: 300kHz
+    /// - 5: 400kHz
+    #[bits(4, from = FskSymbolRate::from_bits)]
+    pub srate: FskSymbolRate,
 
     #[bits(1)]
     __: u8,
@@ -2559,170 +3728,1052 @@ pub struct BbcnPmuq {
 // Enumerations for Register Field Values
 // =============================================================================
 
-/// Device Part Number Values
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum DevicePartNumber {
-    AT86RF215 = 0x34,
-    AT86RF215IQ = 0x35,
-    AT86RF215M = 0x36,
-}
-
-impl DevicePartNumber {
-    pub const fn into_bits(self) -> u8 {
-        self as _
-    }
-
-    pub const fn from_bits(value: u8) -> Self {
-        match value {
-            0x34 => Self::AT86RF215,
-            0x35 => Self::AT86RF215IQ,
-            0x36 => Self::AT86RF215M,
-            _ => Self::AT86RF215, // Default fallback
+/// Declares a register field enum, its `from_bits`/`into_bits` conversions
+/// for bitfield getters and setters, and a checked [`TryFrom<u8>`].
+///
+/// Values the datasheet does not define decode to `Unknown(bits)`, so a
+/// corrupted SPI transfer or an unexpected chip is visible to the caller.
+macro_rules! register_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $( $(#[doc = $vdoc:literal])* $variant:ident = $value:literal, )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub enum $name {
+            $( $(#[doc = $vdoc])* $variant, )*
+            /// Value not defined by the datasheet
+            Unknown(u8),
         }
-    }
-}
 
-/// Chip Reset Commands
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum ChipResetCmd {
-    Reset = 0x07,
-}
+        impl $name {
+            pub const fn into_bits(self) -> u8 {
+                match self {
+                    $( Self::$variant => $value, )*
+                    Self::Unknown(value) => value,
+                }
+            }
 
-impl ChipResetCmd {
-    pub const fn into_bits(self) -> u8 {
-        self as _
-    }
+            pub const fn from_bits(value: u8) -> Self {
+                match value {
+                    $( $value => Self::$variant, )*
+                    _ => Self::Unknown(value),
+                }
+            }
 
-    pub const fn from_bits(value: u8) -> Self {
-        match value {
-            0x07 => Self::Reset,
-            _ => Self::Reset,
-            // TODO check if Chip reset needs other enum fields
-            // I think it needs another entry for other to not match to Self::Reset
-        }
+            /// Returns true if the value is not defined by the datasheet.
+            pub const fn is_unknown(self) -> bool {
+                matches!(self, Self::Unknown(_))
+            }
+
+            /// Meaning of the value as documented in the datasheet
+            pub const fn description(self) -> &'static str {
+                match self {
+                    $(
+                        Self::$variant => {
+                            variant_description(concat!($($vdoc),*), stringify!($variant))
+                        }
+                    )*
+                    Self::Unknown(_) => "unknown",
+                }
+            }
+        }
+
+        impl TryFrom<u8> for $name {
+            type Error = DecodeError;
+
+            fn try_from(value: u8) -> Result<Self, DecodeError> {
+                match Self::from_bits(value) {
+                    Self::Unknown(value) => Err(DecodeError {
+                        name: stringify!($name),
+                        value,
+                    }),
+                    known => Ok(known),
+                }
+            }
+        }
+
+        impl From<$name> for u8 {
+            fn from(value: $name) -> u8 {
+                value.into_bits()
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.description())
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl FieldBits for $name {
+            fn field_bits(&self) -> u64 {
+                self.into_bits() as u64
+            }
+        }
+
+        impl IntoFieldValue for $name {
+            fn field_value(&self) -> FieldValue {
+                FieldValue::Enum {
+                    bits: self.into_bits(),
+                    meaning: self.description(),
+                }
+            }
+        }
+    };
+}
+
+register_enum! {
+    /// Device Part Number Values
+    pub enum DevicePartNumber {
+        AT86RF215 = 0x34,
+        AT86RF215IQ = 0x35,
+        AT86RF215M = 0x36,
     }
 }
 
-/// Transceiver States
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum TransceiverState {
-    TrxOff = 2,
-    TxPrep = 3,
-    Tx = 4,
-    Rx = 5,
-    Transition = 6,
-    Reset = 7,
-}
-
-impl TransceiverState {
-    pub const fn into_bits(self) -> u8 {
-        self as _
-    }
-
-    pub const fn from_bits(value: u8) -> Self {
-        match value {
-            2 => Self::TrxOff,
-            3 => Self::TxPrep,
-            4 => Self::Tx,
-            5 => Self::Rx,
-            6 => Self::Transition,
-            7 => Self::Reset,
-            _ => Self::TrxOff, // Default fallback
-        }
+register_enum! {
+    /// Chip Reset Commands
+    pub enum ChipResetCmd {
+        /// No action, the value of RF_RST after reset
+        Nop = 0x00,
+        /// Reset the whole chip
+        Reset = 0x07,
+    }
+}
+
+register_enum! {
+    /// Transceiver States
+    pub enum TransceiverState {
+        TrxOff = 2,
+        TxPrep = 3,
+        Tx = 4,
+        Rx = 5,
+        Transition = 6,
+        Reset = 7,
+    }
+}
+
+register_enum! {
+    pub enum TransceiverCmd {
+        Nop = 0,
+        Sleep = 1,
+        TrxOff = 2,
+        TxPrep = 3,
+        Tx = 4,
+        Rx = 5,
+        Reset = 7,
+    }
+}
+
+register_enum! {
+    /// Chip Operating Modes
+    pub enum ChipMode {
+        /// RF enabled, both basebands enabled, I/Q IF disabled
+        BasebandMode = 0,
+        /// RF enabled, both basebands disabled, I/Q IF enabled
+        IqRadioMode = 1,
+        /// RF09 I/Q mode, RF24 baseband mode
+        Rf09IqRf24Bb = 4,
+        /// RF09 baseband mode, RF24 I/Q mode
+        Rf09BbRf24Iq = 5,
+    }
+}
+
+register_enum! {
+    /// Energy Detection Modes
+    pub enum EnergyDetectionMode {
+        /// Automatic trigger when AGC held
+        Auto = 0,
+        /// Single measurement
+        Single = 1,
+        /// Continuous measurements
+        Continuous = 2,
+        /// Disabled
+        Off = 3,
+    }
+}
+
+register_enum! {
+    /// Pad and CLKO Output Driver Strength
+    pub enum DriverStrength {
+        /// 2mA
+        Ma2 = 0,
+        /// 4mA
+        Ma4 = 1,
+        /// 6mA
+        Ma6 = 2,
+        /// 8mA
+        Ma8 = 3,
+    }
+}
+
+register_enum! {
+    /// CLKO Output Frequency
+    pub enum ClockOutput {
+        /// Clock output disabled
+        Off = 0,
+        /// 26MHz
+        Mhz26 = 1,
+        /// 32MHz
+        Mhz32 = 2,
+        /// 16MHz
+        Mhz16 = 3,
+        /// 8MHz
+        Mhz8 = 4,
+        /// 4MHz
+        Mhz4 = 5,
+        /// 2MHz
+        Mhz2 = 6,
+        /// 1MHz
+        Mhz1 = 7,
+    }
+}
+
+register_enum! {
+    /// Channel Setting Modes
+    pub enum ChannelMode {
+        /// IEEE compliant, (CCF0 + CN × CS) × 25kHz
+        Ieee = 0,
+        /// Fine resolution 389.5-510MHz, ~99Hz stepping
+        Fine389 = 1,
+        /// Fine resolution 779-1020MHz, ~198Hz stepping
+        Fine779 = 2,
+        /// Fine resolution 2400-2483.5MHz, ~397Hz stepping
+        Fine2400 = 3,
+    }
+}
+
+register_enum! {
+    /// Receiver Bandwidth and IF Frequency
+    pub enum ReceiverBandwidth {
+        /// 160kHz @ 250kHz IF
+        Khz160 = 0,
+        /// 200kHz @ 250kHz IF
+        Khz200 = 1,
+        /// 250kHz @ 250kHz IF
+        Khz250 = 2,
+        /// 320kHz @ 500kHz IF
+        Khz320 = 3,
+        /// 400kHz @ 500kHz IF
+        Khz400 = 4,
+        /// 500kHz @ 500kHz IF
+        Khz500 = 5,
+        /// 630kHz @ 1000kHz IF
+        Khz630 = 6,
+        /// 800kHz @ 1000kHz IF
+        Khz800 = 7,
+        /// 1000kHz @ 1000kHz IF
+        Khz1000 = 8,
+        /// 1250kHz @ 2000kHz IF
+        Khz1250 = 9,
+        /// 1600kHz @ 2000kHz IF
+        Khz1600 = 10,
+        /// 2000kHz @ 2000kHz IF
+        Khz2000 = 11,
+    }
+}
+
+register_enum! {
+    /// RX/TX Front-End Sample Rate
+    pub enum SampleRate {
+        /// 4000kHz
+        Khz4000 = 1,
+        /// 2000kHz
+        Khz2000 = 2,
+        /// 4000/3 kHz
+        Khz1333 = 3,
+        /// 1000kHz
+        Khz1000 = 4,
+        /// 800kHz
+        Khz800 = 5,
+        /// 2000/3 kHz
+        Khz666 = 6,
+        /// 500kHz
+        Khz500 = 8,
+        /// 400kHz
+        Khz400 = 10,
+    }
+}
+
+register_enum! {
+    /// RX/TX Filter Cut-off Frequency, relative to fS/2
+    pub enum RelativeCutoff {
+        /// 0.25 × fS/2
+        Quarter = 0,
+        /// 0.375 × fS/2
+        ThreeEighths = 1,
+        /// 0.5 × fS/2
+        Half = 2,
+        /// 0.75 × fS/2
+        ThreeQuarters = 3,
+        /// 1.0 × fS/2 (bypass)
+        Full = 4,
+    }
+}
+
+register_enum! {
+    /// Energy Detection Duration Time Basis
+    pub enum EdDurationBasis {
+        /// 2µs
+        Us2 = 0,
+        /// 8µs
+        Us8 = 1,
+        /// 32µs
+        Us32 = 2,
+        /// 128µs
+        Us128 = 3,
+    }
+}
+
+register_enum! {
+    /// Transmitter Analog Low-Pass Cut-off Frequency
+    pub enum TxCutoff {
+        /// 80kHz
+        Khz80 = 0,
+        /// 100kHz
+        Khz100 = 1,
+        /// 125kHz
+        Khz125 = 2,
+        /// 160kHz
+        Khz160 = 3,
+        /// 200kHz
+        Khz200 = 4,
+        /// 250kHz
+        Khz250 = 5,
+        /// 315kHz
+        Khz315 = 6,
+        /// 400kHz
+        Khz400 = 7,
+        /// 500kHz
+        Khz500 = 8,
+        /// 625kHz
+        Khz625 = 9,
+        /// 800kHz
+        Khz800 = 10,
+        /// 1000kHz
+        Khz1000 = 11,
+    }
+}
+
+register_enum! {
+    /// Power Amplifier Ramp Time
+    pub enum PaRampTime {
+        /// 4µs
+        Us4 = 0,
+        /// 8µs
+        Us8 = 1,
+        /// 16µs
+        Us16 = 2,
+        /// 32µs
+        Us32 = 3,
+    }
+}
+
+register_enum! {
+    /// Baseband PHY Types
+    pub enum PhyType {
+        /// Baseband PHY off
+        Off = 0,
+        /// MR-FSK
+        Fsk = 1,
+        /// MR-OFDM
+        Ofdm = 2,
+        /// MR-O-QPSK
+        Oqpsk = 3,
+    }
+}
+
+register_enum! {
+    /// O-QPSK Chip Frequency
+    pub enum OqpskChipFrequency {
+        /// 100kchip/s
+        Kchips100 = 0,
+        /// 200kchip/s
+        Kchips200 = 1,
+        /// 1000kchip/s
+        Kchips1000 = 2,
+        /// 2000kchip/s
+        Kchips2000 = 3,
+    }
+}
+
+register_enum! {
+    /// FSK Symbol Rate
+    pub enum FskSymbolRate {
+        /// 50kHz
+        Khz50 = 0,
+        /// 100kHz
+        Khz100 = 1,
+        /// 150kHz
+        Khz150 = 2,
+        /// 200kHz
+        Khz200 = 3,
+        /// 300kHz
+        Khz300 = 4,
+        /// 400kHz
+        Khz400 = 5,
     }
 }
 
+register_enum! {
+    /// MR-OFDM Options
+    pub enum OfdmOption {
+        /// Option 1
+        Option1 = 0,
+        /// Option 2
+        Option2 = 1,
+        /// Option 3
+        Option3 = 2,
+        /// Option 4
+        Option4 = 3,
+    }
+}
+
+// =============================================================================
+// Reset Values
+// =============================================================================
+
+/// Implements `reset()`, the value after power-on or an RF_RST chip reset,
+/// for register types with a single reset value.
+macro_rules! reset_values {
+    ($($ty:ident => $bits:expr,)*) => {
+        $(
+            impl $ty {
+                /// Value after power-on or chip reset
+                pub const fn reset() -> Self {
+                    Self::from_bits($bits)
+                }
+            }
+        )*
+    };
+}
+
+// Values from the register summary tables of the AT86RF215 datasheet
+// (Microchip DS40001914)
+reset_values! {
+    // Common chip registers
+    RfPn => 0x34,
+    RfVn => 0x03,
+    RfRst => 0x00,
+    // RF_CFG: IRQMM = 1, IRQP = 0 (active high), DRV = 2mA
+    RfCfg => 0x08,
+    RfClko => 0x09,
+    RfBmdvc => 0x05,
+    // RF_XOC: FS = 1, TRIM = 0xF
+    RfXoc => 0x1F,
+    RfIqifc0 => 0x16,
+    RfIqifc1 => 0x02,
+    RfIqifc2 => 0x00,
+
+    // Radio registers, RFn_STATE is TRXOFF once the reset has completed
+    RfnIrqs => 0x00,
+    RfnIrqm => 0x00,
+    RfnAuxs => 0x02,
+    RfnState => 0x02,
+    RfnCmd => 0x00,
+    RfnCs => 0x08,
+    RfnCnl => 0x00,
+    RfnCnm => 0x00,
+    RfnRxbwc => 0x09,
+    RfnRxdfe => 0x01,
+    RfnAgcc => 0x01,
+    RfnAgcs => 0x77,
+    RfnRssi => 0x7F,
+    RfnEdc => 0x00,
+    RfnEdd => 0x7A,
+    RfnEdv => 0x7F,
+    RfnPll => 0x09,
+    RfnPllcf => 0x00,
+    RfnTxcutc => 0x0B,
+    RfnTxdfe => 0x01,
+    RfnPac => 0x7F,
+    RfnRndv => 0x00,
+    RfnPadfe => 0x00,
+    RfnTxci => 0x00,
+    RfnTxcq => 0x00,
+    RfnTxdaci => 0x00,
+    RfnTxdacq => 0x00,
+
+    // Baseband registers
+    BbcnPc => 0x1D,
+    BbcnPs => 0x00,
+    BbcnRxfl => 0x0000,
+    BbcnTxfl => 0x0000,
+    BbcnFbl => 0x0000,
+    BbcnFbli => 0x07FF,
+    BbcnOfdmphrtx => 0x00,
+    BbcnOfdmphrrx => 0x00,
+    BbcnOfdmc => 0x00,
+    BbcnOfdmsw => 0x00,
+    BbcnOqpskc0 => 0x03,
+    BbcnOqpskc1 => 0x47,
+    BbcnOqpskc2 => 0x05,
+    BbcnOqpskc3 => 0x00,
+    BbcnOqpskphrtx => 0x00,
+    BbcnOqpskphrrx => 0x00,
+    BbcnAfc0 => 0x00,
+    BbcnAfc1 => 0x00,
+    BbcnAfftm => 0x00,
+    BbcnAffvm => 0x00,
+    BbcnAfs => 0x00,
+    BbcnMacea => 0x0000_0000_0000_0000,
+    BbcnMacpid => 0xFFFF,
+    BbcnMacsha => 0xFFFF,
+    BbcnAmcs => 0x00,
+    BbcnAmedt => 0xB2,
+    BbcnAmaackpd => 0x01,
+    BbcnAmaackt => 0x0000,
+    BbcnFskc0 => 0xD6,
+    BbcnFskc1 => 0x01,
+    BbcnFskc2 => 0x41,
+    BbcnFskc3 => 0x85,
+    BbcnFskc4 => 0x0A,
+    BbcnFskpll => 0x08,
+    BbcnFskphrtx => 0x00,
+    BbcnFskphrrx => 0x00,
+    BbcnFskrpc => 0x00,
+    BbcnFskrpcont => 0x00,
+    BbcnFskrpcofft => 0x00,
+    BbcnFskrrxfl => 0x0000,
+    BbcnFskdm => 0x00,
+    BbcnCntc => 0x00,
+    BbcnCnt => 0x0000_0000,
+    BbcnIrqs => 0x00,
+    BbcnIrqm => 0x00,
+    BbcnPmuc => 0x00,
+    BbcnPmuval => 0x00,
+    BbcnPmuqf => 0x00,
+    BbcnPmui => 0x00,
+    BbcnPmuq => 0x00,
+}
+
+impl RfnCcf0 {
+    /// RF09_CCF0 after reset, 903.2MHz
+    pub const fn reset_rf09() -> Self {
+        Self::from_bits(0x8D20)
+    }
+
+    /// RF24_CCF0 after reset
+    pub const fn reset_rf24() -> Self {
+        Self::from_bits(0x8CF8)
+    }
+}
+
+impl BbcnFsksfd {
+    /// BBCn_FSKSFD0 after reset
+    pub const fn reset_sfd0() -> Self {
+        Self::from_bits(0x7209)
+    }
+
+    /// BBCn_FSKSFD1 after reset
+    pub const fn reset_sfd1() -> Self {
+        Self::from_bits(0xF672)
+    }
+}
+
+impl BbcnFskpe {
+    /// BBCn_FSKPE0 after reset
+    pub const fn reset_pe0() -> Self {
+        Self::from_bits(0x02)
+    }
+
+    /// BBCn_FSKPE1 after reset
+    pub const fn reset_pe1() -> Self {
+        Self::from_bits(0x03)
+    }
+
+    /// BBCn_FSKPE2 after reset
+    pub const fn reset_pe2() -> Self {
+        Self::from_bits(0xFC)
+    }
+}
+
+// =============================================================================
+// Pretty Printing
+// =============================================================================
+
+/// Register contents that can be shown in a register dump
+pub trait RegisterValue: fmt::Display {
+    /// Raw register value
+    fn bits(&self) -> u64;
+
+    /// Number of named fields
+    fn field_count(&self) -> usize;
+
+    /// Bits the chip updates on its own, see [`SideEffects::VOLATILE`]
+    fn volatile_bits(&self) -> u64;
+
+    /// Named field at `index`, in bit order
+    fn field(&self, index: usize) -> Option<Field>;
+}
+
+impl dyn RegisterValue + '_ {
+    /// Named fields, in bit order
+    pub fn fields(&self) -> impl Iterator<Item = Field> + '_ {
+        (0..self.field_count()).filter_map(|index| self.field(index))
+    }
+}
+
+/// Decoded value of a register field
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum TransceiverCmd {
-    Nop = 0,
-    Sleep = 1,
-    TrxOff = 2,
-    TxPrep = 3,
-    Tx = 4,
-    Rx = 5,
-    Reset = 7,
-}
-
-impl TransceiverCmd {
-    pub const fn into_bits(self) -> u8 {
-        self as _
-    }
-
-    pub const fn from_bits(value: u8) -> Self {
-        match value {
-            0 => Self::Nop,
-            1 => Self::Sleep,
-            2 => Self::TrxOff,
-            3 => Self::TxPrep,
-            4 => Self::Tx,
-            5 => Self::Rx,
-            7 => Self::Reset,
-            _ => Self::Nop, // Default fallback
+pub enum FieldValue {
+    Bool(bool),
+    Unsigned(u64),
+    Signed(i64),
+    /// Field enum, its raw bits and their meaning from the datasheet
+    Enum {
+        bits: u8,
+        meaning: &'static str,
+    },
+}
+
+impl fmt::Display for FieldValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bool(value) => write!(f, "{}", *value as u8),
+            Self::Unsigned(value) => write!(f, "{value}"),
+            Self::Signed(value) => write!(f, "{value}"),
+            Self::Enum { bits, meaning } => write!(f, "{bits} ({meaning})"),
         }
     }
 }
 
-/// Chip Operating Modes
+/// Named register field, shown as `REGISTER.FIELD = value`
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum ChipMode {
-    /// RF enabled, both basebands enabled, I/Q IF disabled
-    BasebandMode = 0,
-    /// RF enabled, both basebands disabled, I/Q IF enabled
-    IqRadioMode = 1,
-    /// RF09 I/Q mode, RF24 baseband mode
-    Rf09IqRf24Bb = 4,
-    /// RF09 baseband mode, RF24 I/Q mode
-    Rf09BbRf24Iq = 5,
-}
-
-impl ChipMode {
-    pub const fn into_bits(self) -> u8 {
-        self as _
-    }
-
-    pub const fn from_bits(value: u8) -> Self {
-        match value {
-            0 => Self::BasebandMode,
-            1 => Self::IqRadioMode,
-            4 => Self::Rf09IqRf24Bb,
-            5 => Self::Rf09BbRf24Iq,
-            _ => Self::BasebandMode, // Default fallback
+pub struct Field {
+    /// Register name without the band prefix, e.g. `RXBWC`
+    pub register: &'static str,
+    pub name: &'static str,
+    pub value: FieldValue,
+}
+
+impl fmt::Display for Field {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{} = {}", self.register, self.name, self.value)
+    }
+}
+
+/// Conversion of a field getter result to a [`FieldValue`]
+trait IntoFieldValue {
+    fn field_value(&self) -> FieldValue;
+}
+
+impl IntoFieldValue for bool {
+    fn field_value(&self) -> FieldValue {
+        FieldValue::Bool(*self)
+    }
+}
+
+impl IntoFieldValue for i8 {
+    fn field_value(&self) -> FieldValue {
+        FieldValue::Signed(*self as i64)
+    }
+}
+
+macro_rules! unsigned_field_value {
+    ($($ty:ty),*) => {
+        $(
+            impl IntoFieldValue for $ty {
+                fn field_value(&self) -> FieldValue {
+                    FieldValue::Unsigned(*self as u64)
+                }
+            }
+        )*
+    };
+}
+
+unsigned_field_value!(u8, u16, u32, u64);
+
+/// Returns the variant description taken from its doc comment, or its name.
+const fn variant_description(doc: &'static str, name: &'static str) -> &'static str {
+    let doc = doc.trim_ascii();
+    if doc.is_empty() { name } else { doc }
+}
+
+/// Field name from the uppercased field identifier, `MOD_` is shown as `MOD`
+fn field_name(ident: &'static str) -> &'static str {
+    ident.trim_end_matches('_')
+}
+
+/// Writes every field, separated by a comma, or a new line with the
+/// alternate `{:#}` flag.
+fn fmt_fields(value: &dyn RegisterValue, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    for (index, field) in value.fields().enumerate() {
+        if index > 0 {
+            f.write_str(if f.alternate() { "\n" } else { ", " })?;
         }
+        write!(f, "{field}")?;
+    }
+    Ok(())
+}
+
+/// Implements `Display`, [`RegisterValue`], [`WriteMask`] and, with the
+/// `serde` feature, field-wise `Serialize`/`Deserialize` for register
+/// bitfields, listing the named fields in bit order. `#[ro]` marks the fields
+/// declared with `access = RO` and `#[trigger]` the fields whose write starts an
+/// action. `#[commit]` and `#[command]` registers are written in a later
+/// [`WriteStage`], `#[clear_on_read]` registers are cleared by a read.
+macro_rules! register_fields {
+    (@writable ro) => { 0 };
+    (@writable $($access:ident)?) => { u64::MAX };
+    (@trigger trigger) => { u64::MAX };
+    (@trigger $($access:ident)?) => { 0 };
+    (@stage commit) => { WriteStage::Commit };
+    (@stage command) => { WriteStage::Command };
+    (@stage $($kind:ident)?) => { WriteStage::Config };
+    (@clears_on_read clear_on_read) => { true };
+    (@clears_on_read $($kind:ident)?) => { false };
+    (@volatile constant) => { false };
+    (@volatile $($kind:ident)?) => { true };
+    ($(
+        $(#[$kind:ident])?
+        $ty:ident $name:literal { $( $(#[$access:ident])? $field:ident: $fty:ty ),* }
+    )*) => {
+        $(
+            impl fmt::Display for $ty {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt_fields(self, f)
+                }
+            }
+
+            impl RegisterValue for $ty {
+                fn bits(&self) -> u64 {
+                    self.into_bits().into()
+                }
+
+                fn field_count(&self) -> usize {
+                    [$( stringify!($field) ),*].len()
+                }
+
+                fn volatile_bits(&self) -> u64 {
+                    <Self as SideEffects>::VOLATILE
+                }
+
+                fn field(&self, index: usize) -> Option<Field> {
+                    let fields = [$(
+                        paste::paste! {
+                            Field {
+                                register: $name,
+                                name: field_name(stringify!([<$field:upper>])),
+                                value: self.$field().field_value(),
+                            }
+                        }
+                    ),*];
+                    fields.get(index).copied()
+                }
+            }
+
+            impl WriteOrder for $ty {
+                const STAGE: WriteStage = register_fields!(@stage $($kind)?);
+            }
+
+            paste::paste! {
+                impl WriteMask for $ty {
+                    const WRITABLE: u64 = 0 $(
+                        | register_fields!(@writable $($access)?)
+                            & field_mask(Self::[<$field:upper _BITS>], Self::[<$field:upper _OFFSET>])
+                    )*;
+                    const RESERVED: u64 = Self::new().into_bits() as u64 & !(0 $(
+                        | field_mask(Self::[<$field:upper _BITS>], Self::[<$field:upper _OFFSET>])
+                    )*);
+                }
+
+                impl SideEffects for $ty {
+                    const CLEARS_ON_READ: bool = register_fields!(@clears_on_read $($kind)?);
+                    const TRIGGER: u64 = 0 $(
+                        | register_fields!(@trigger $($access)?)
+                            & field_mask(Self::[<$field:upper _BITS>], Self::[<$field:upper _OFFSET>])
+                    )*;
+                    const VOLATILE: u64 = if register_fields!(@volatile $($kind)?) {
+                        (0 $(
+                            | field_mask(Self::[<$field:upper _BITS>], Self::[<$field:upper _OFFSET>])
+                        )*) & !<Self as WriteMask>::WRITABLE
+                    } else {
+                        0
+                    };
+                }
+            }
+
+            #[cfg(feature = "serde")]
+            const _: () = {
+                #[derive(serde::Serialize, serde::Deserialize)]
+                #[serde(deny_unknown_fields)]
+                struct Fields {
+                    $( $field: $fty, )*
+                }
+
+                impl serde::Serialize for $ty {
+                    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                        Fields {
+                            $( $field: self.$field(), )*
+                        }
+                        .serialize(serializer)
+                    }
+                }
+
+                // Assembled from the raw field bits so read-only fields round-trip too
+                impl<'de> serde::Deserialize<'de> for $ty {
+                    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                        let fields = Fields::deserialize(deserializer)?;
+                        let mut bits: u64 = 0;
+                        $(
+                            paste::paste! {
+                                bits |= field_bits(
+                                    stringify!([<$field:upper>]),
+                                    fields.$field.field_bits(),
+                                    Self::[<$field:upper _BITS>],
+                                )
+                                .map_err(serde::de::Error::custom)?
+                                    << Self::[<$field:upper _OFFSET>];
+                            }
+                        )*
+                        Ok(Self::from_bits(bits as _))
+                    }
+                }
+            };
+        )*
+    };
+}
+
+register_fields! {
+    #[constant]
+    RfPn "RF_PN" { #[ro] pn: DevicePartNumber }
+    #[constant]
+    RfVn "RF_VN" { #[ro] vn: u8 }
+    #[command]
+    RfRst "RF_RST" { #[trigger] cmd: ChipResetCmd }
+    RfCfg "RF_CFG" { drv: DriverStrength, irqp: bool, irqmm: bool }
+    RfClko "RF_CLKO" { os: ClockOutput, drv: DriverStrength }
+    RfBmdvc "RF_BMDVC" { bmth: u8, bmr: bool, bmen: bool }
+    RfXoc "RF_XOC" { trim: u8, fs: u8 }
+    RfIqifc0 "RF_IQIFC0" { eec: bool, cmv1v2: bool, cmv: u8, drv: u8, #[ro] sf: bool, extlb: bool }
+    RfIqifc1 "RF_IQIFC1" { skewdrv: u8, chpm: ChipMode, #[ro] failsf: bool }
+    RfIqifc2 "RF_IQIFC2" { #[ro] sync: bool }
+    #[clear_on_read]
+    RfnIrqs "IRQS" { #[ro] wakeup: bool, #[ro] trxrdy: bool, #[ro] edc: bool, #[ro] batlow: bool, #[ro] trxerr: bool, #[ro] iqifsf: bool }
+    RfnIrqm "IRQM" { wakeup: bool, trxrdy: bool, edc: bool, batlow: bool, trxerr: bool, iqifsf: bool }
+    RfnAuxs "AUXS" { pavc: u8, ave: bool, aven: bool, agcmap: bool, extlnabyp: bool }
+    RfnState "STATE" { #[ro] state: TransceiverState }
+    RfnCs "CS" { cs: u8 }
+    RfnCcf0 "CCF0" { ccf0: u16 }
+    RfnCnl "CNL" { cnl: u8 }
+    #[commit]
+    RfnCnm "CNM" { cnh: bool, cm: ChannelMode }
+    RfnRxbwc "RXBWC" { bw: ReceiverBandwidth, ifs: bool, ifi: bool }
+    RfnRxdfe "RXDFE" { sr: SampleRate, rcut: RelativeCutoff }
+    RfnAgcc "AGCC" { en: bool, frzc: bool, #[ro] frzs: bool, #[trigger] rst: bool, avgs: u8, agci: bool }
+    RfnAgcs "AGCS" { gcw: u8, tgt: u8 }
+    RfnRssi "RSSI" { #[ro] rssi: i8 }
+    RfnEdc "EDC" { edm: EnergyDetectionMode }
+    RfnEdd "EDD" { dtb: EdDurationBasis, df: u8 }
+    RfnEdv "EDV" { #[ro] edv: i8 }
+    RfnPll "PLL" { #[ro] ls: bool, lbw: u8 }
+    RfnPllcf "PLLCF" { #[ro] cf: u8 }
+    RfnTxcutc "TXCUTC" { lpfcut: TxCutoff, paramp: PaRampTime }
+    RfnTxdfe "TXDFE" { sr: SampleRate, dm: bool, rcut: RelativeCutoff }
+    RfnPac "PAC" { txpwr: u8, pacur: u8 }
+    RfnRndv "RNDV" { #[ro] rndv: u8 }
+    RfnPadfe "PADFE" { padfe: bool }
+    RfnTxci "TXCI" { dcoi: u8 }
+    RfnTxcq "TXCQ" { dcoq: u8 }
+    RfnTxdaci "TXDACI" { txdacid: u8, entxdacid: bool }
+    RfnTxdacq "TXDACQ" { txdacqd: u8, entxdacqd: bool }
+    BbcnPc "PC" { pt: PhyType, bben: bool, fcst: bool, txafcs: bool, #[ro] fcsok: bool, fcsfe: bool, ctx: bool }
+    BbcnPs "PS" { #[ro] txur: bool }
+    BbcnRxfl "RXFL" { #[ro] rxfl: u16 }
+    BbcnTxfl "TXFL" { txfl: u16 }
+    BbcnFbl "FBL" { #[ro] fbl: u16 }
+    BbcnFbli "FBLI" { fbli: u16 }
+    BbcnOfdmphrtx "OFDMPHRTX" { mcs: u8, rb5: bool, rb17: bool, rb18: bool, rb21: bool }
+    BbcnOfdmphrrx "OFDMPHRRX" { #[ro] mcs: u8, #[ro] spc: bool, #[ro] rb5: bool, #[ro] rb17: bool, #[ro] rb18: bool, #[ro] rb21: bool }
+    BbcnOfdmc "OFDMC" { opt: OfdmOption, poi: bool, lfo: bool, sstx: u8, ssrx: u8 }
+    BbcnOfdmsw "OFDMSW" { rxo: u8, pdt: u8 }
+    BbcnOqpskc0 "OQPSKC0" { fchip: OqpskChipFrequency, mod_: bool, dm: bool }
+    BbcnOqpskc1 "OQPSKC1" { pdt0: u8, pdt1: u8, rxoleg: bool, rxo: bool }
+    BbcnOqpskc2 "OQPSKC2" { rxm: u8, fcstleg: bool, enprop: bool, rpc: bool, spc: bool }
+    BbcnOqpskc3 "OQPSKC3" { nsfd: u8, hrleg: bool }
+    BbcnOqpskphrtx "OQPSKPHRTX" { leg: bool, mod_: u8, rb0: bool, ppdut: bool }
+    BbcnOqpskphrrx "OQPSKPHRRX" { #[ro] leg: bool, #[ro] mod_: u8, #[ro] rb0: bool, #[ro] ppdut: bool }
+    BbcnAfc0 "AFC0" { afen0: bool, afen1: bool, afen2: bool, afen3: bool, pm: bool }
+    BbcnAfc1 "AFC1" { panc: u8, mrft: u8 }
+    BbcnAfftm "AFFTM" { afftm: u8 }
+    BbcnAffvm "AFFVM" { affvm: u8 }
+    BbcnAfs "AFS" { #[ro] am0: bool, #[ro] am1: bool, #[ro] am2: bool, #[ro] am3: bool, #[ro] em: bool }
+    BbcnMacea "MACEA" { macea: u64 }
+    BbcnMacpid "MACPID" { macpid: u16 }
+    BbcnMacsha "MACSHA" { macsha: u16 }
+    BbcnAmcs "AMCS" { tx2rx: bool, ccatx: bool, ccaed: bool, aack: bool, aacks: bool, aackdr: bool, aackfa: bool, aackft: bool }
+    BbcnAmedt "AMEDT" { amedt: u8 }
+    BbcnAmaackpd "AMAACKPD" { pd0: bool, pd1: bool, pd2: bool, pd3: bool }
+    BbcnAmaackt "AMAACKT" { amaackt: u16 }
+    BbcnFskc0 "FSKC0" { mord: bool, midx: u8, midxs: u8, bt: u8 }
+    BbcnFskc1 "FSKC1" { srate: FskSymbolRate, fi: bool, fskplh: u8 }
+    BbcnFskc2 "FSKC2" { fecie: bool, fecs: bool, pri: bool, mse: bool, rxpto: bool, rxo: u8, pdtm: bool }
+    BbcnFskc3 "FSKC3" { pdt: u8, sfdt: u8 }
+    BbcnFskc4 "FSKC4" { csfd0: u8, csfd1: u8, rawrbit: bool, sfd32: bool, sfdq: bool }
+    BbcnFskpll "FSKPLL" { fskpll: u8 }
+    BbcnFsksfd "FSKSFD" { fsksfd: u16 }
+    BbcnFskphrtx "FSKPHRTX" { rb1: bool, rb2: bool, dw: bool, sfd: bool }
+    BbcnFskphrrx "FSKPHRRX" { #[ro] rb1: bool, #[ro] rb2: bool, #[ro] dw: bool, #[ro] sfd: bool, #[ro] ms: bool, #[ro] fcst: bool }
+    BbcnFskrpc "FSKRPC" { baset: u8, en: bool }
+    BbcnFskrpcont "FSKRPCONT" { fskrpcont: u8 }
+    BbcnFskrpcofft "FSKRPCOFFT" { fskrpcofft: u8 }
+    BbcnFskrrxfl "FSKRRXFL" { #[ro] fskrrxfl: u16 }
+    BbcnFskdm "FSKDM" { en: bool, pe: bool }
+    BbcnFskpe "FSKPE" { fskpe: u8 }
+    BbcnCntc "CNTC" { en: bool, rstrxs: bool, rsttxs: bool, caprxs: bool, captxs: bool }
+    BbcnCnt "CNT" { #[ro] cnt: u32 }
+    #[clear_on_read]
+    BbcnIrqs "IRQS" { #[ro] rxfs: bool, #[ro] rxfe: bool, #[ro] rxam: bool, #[ro] rxem: bool, #[ro] txfe: bool, #[ro] agch: bool, #[ro] agcr: bool, #[ro] fbli: bool }
+    BbcnIrqm "IRQM" { rxfs: bool, rxfe: bool, rxam: bool, rxem: bool, txfe: bool, agch: bool, agcr: bool, fbli: bool }
+    BbcnPmuc "PMUC" { en: bool, avg: bool, #[ro] sync: u8, fed: bool, iqsel: bool, ccfts: bool }
+    BbcnPmuval "PMUVAL" { #[ro] pmuval: u8 }
+    BbcnPmuqf "PMUQF" { #[ro] pmuqf: i8 }
+    BbcnPmui "PMUI" { #[ro] pmui: i8 }
+    BbcnPmuq "PMUQ" { #[ro] pmuq: i8 }
+}
+
+// RFn_CMD.CMD is write-only and has no getter
+impl fmt::Display for RfnCmd {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_fields(self, f)
     }
 }
 
-/// Energy Detection Modes
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum EnergyDetectionMode {
-    /// Automatic trigger when AGC held
-    Auto = 0,
-    /// Single measurement
-    Single = 1,
-    /// Continuous measurements
-    Continuous = 2,
-    /// Disabled
-    Off = 3,
-}
-
-impl EnergyDetectionMode {
-    pub const fn into_bits(self) -> u8 {
-        self as _
-    }
-
-    pub const fn from_bits(value: u8) -> Self {
-        match value {
-            0 => Self::Auto,
-            1 => Self::Single,
-            2 => Self::Continuous,
-            3 => Self::Off,
-            _ => Self::Off, // Default fallback
+impl RegisterValue for RfnCmd {
+    fn bits(&self) -> u64 {
+        self.into_bits().into()
+    }
+
+    fn field_count(&self) -> usize {
+        1
+    }
+
+    fn volatile_bits(&self) -> u64 {
+        <Self as SideEffects>::VOLATILE
+    }
+
+    fn field(&self, index: usize) -> Option<Field> {
+        (index == 0).then(|| Field {
+            register: "CMD",
+            name: "CMD",
+            value: TransceiverCmd::from_bits(self.into_bits() & 0x07).field_value(),
+        })
+    }
+}
+
+impl WriteOrder for RfnCmd {
+    const STAGE: WriteStage = WriteStage::Command;
+}
+
+impl SideEffects for RfnCmd {
+    const CLEARS_ON_READ: bool = false;
+    const TRIGGER: u64 = field_mask(Self::CMD_BITS, Self::CMD_OFFSET);
+    const VOLATILE: u64 = 0;
+}
+
+impl WriteMask for RfnCmd {
+    const WRITABLE: u64 = field_mask(Self::CMD_BITS, Self::CMD_OFFSET);
+    const RESERVED: u64 = 0;
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for RfnCmd {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("RfnCmd", 1)?;
+        state.serialize_field("cmd", &TransceiverCmd::from_bits(self.into_bits() & 0x07))?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for RfnCmd {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        #[serde(deny_unknown_fields)]
+        struct Fields {
+            cmd: TransceiverCmd,
         }
+
+        let fields = Fields::deserialize(deserializer)?;
+        let bits =
+            field_bits("CMD", fields.cmd.field_bits(), 3).map_err(serde::de::Error::custom)?;
+        Ok(Self::from_bits(bits as u8))
+    }
+}
+
+/// Raw bits of a field value, used to assemble deserialized registers
+#[cfg(feature = "serde")]
+trait FieldBits {
+    fn field_bits(&self) -> u64;
+}
+
+#[cfg(feature = "serde")]
+impl FieldBits for bool {
+    fn field_bits(&self) -> u64 {
+        *self as u64
+    }
+}
+
+#[cfg(feature = "serde")]
+impl FieldBits for i8 {
+    fn field_bits(&self) -> u64 {
+        *self as u8 as u64
+    }
+}
+
+#[cfg(feature = "serde")]
+macro_rules! unsigned_field_bits {
+    ($($ty:ty),*) => {
+        $(
+            impl FieldBits for $ty {
+                fn field_bits(&self) -> u64 {
+                    *self as u64
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "serde")]
+unsigned_field_bits!(u8, u16, u32, u64);
+
+/// Returns `value` if it fits in a field of `bits` bits.
+///
+/// Signed fields are always 8 bits wide, so their two's complement fits too.
+#[cfg(feature = "serde")]
+fn field_bits(field: &'static str, value: u64, bits: usize) -> Result<u64, FieldOverflow> {
+    let mask = u64::MAX >> (64 - bits);
+    if value & !mask == 0 {
+        Ok(value)
+    } else {
+        Err(FieldOverflow { field, value, bits })
+    }
+}
+
+/// A deserialized field value does not fit in the field
+#[cfg(feature = "serde")]
+#[derive(Debug)]
+struct FieldOverflow {
+    field: &'static str,
+    value: u64,
+    bits: usize,
+}
+
+#[cfg(feature = "serde")]
+impl fmt::Display for FieldOverflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} = {} does not fit in {} bits",
+            self.field, self.value, self.bits
+        )
     }
 }
 
@@ -2735,6 +4786,7 @@ mod tests {
     use super::*;
 
     #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn test_bbcn_pmuc_read_only_field() {
         let mut pmuc = BbcnPmuc::new().with_en(true).with_avg(false);
 
@@ -2756,6 +4808,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn test_bbcn_pmuc_writable_fields() {
         let pmuc = BbcnPmuc::new()
             .with_en(true)
@@ -2770,4 +4823,644 @@ mod tests {
         assert_eq!(pmuc.iqsel(), false);
         assert_eq!(pmuc.ccfts(), true);
     }
+
+    #[test]
+    fn test_typed_fields_reset_values() {
+        assert_eq!(RfClko::reset().os(), ClockOutput::Mhz26);
+        assert_eq!(RfClko::reset().drv(), DriverStrength::Ma4);
+        assert_eq!(RfnRxbwc::reset().bw(), ReceiverBandwidth::Khz1250);
+        assert_eq!(RfnRxdfe::reset().sr(), SampleRate::Khz4000);
+        assert_eq!(RfnRxdfe::reset().rcut(), RelativeCutoff::Quarter);
+        assert_eq!(RfnEdd::reset().dtb(), EdDurationBasis::Us32);
+        assert_eq!(RfnTxcutc::reset().lpfcut(), TxCutoff::Khz1000);
+        assert_eq!(RfnTxcutc::reset().paramp(), PaRampTime::Us4);
+        assert_eq!(BbcnPc::reset().pt(), PhyType::Fsk);
+        assert_eq!(BbcnOqpskc0::reset().fchip(), OqpskChipFrequency::Kchips2000);
+        assert_eq!(BbcnFskc1::reset().srate(), FskSymbolRate::Khz100);
+        assert_eq!(BbcnOfdmc::reset().opt(), OfdmOption::Option1);
+    }
+
+    #[test]
+    fn test_typed_fields_encode() {
+        let rxdfe = RfnRxdfe::new()
+            .with_sr(SampleRate::Khz400)
+            .with_rcut(RelativeCutoff::Full);
+        assert_eq!(rxdfe.into_bits(), 0x8A);
+
+        let txcutc = RfnTxcutc::new()
+            .with_lpfcut(TxCutoff::Khz500)
+            .with_paramp(PaRampTime::Us32);
+        assert_eq!(txcutc.into_bits(), 0xC8);
+
+        let pc = BbcnPc::reset().with_pt(PhyType::Oqpsk);
+        assert_eq!(pc.into_bits(), 0x1F);
+        assert_eq!(BbcnPc::from_bits(0x1F).pt(), PhyType::Oqpsk);
+    }
+
+    #[test]
+    fn test_enum_try_from() {
+        assert_eq!(TransceiverState::try_from(5), Ok(TransceiverState::Rx));
+        assert_eq!(
+            TransceiverState::try_from(0),
+            Err(DecodeError {
+                name: "TransceiverState",
+                value: 0
+            })
+        );
+        assert_eq!(
+            DevicePartNumber::try_from(0x42),
+            Err(DecodeError {
+                name: "DevicePartNumber",
+                value: 0x42
+            })
+        );
+        assert_eq!(u8::from(SampleRate::Khz500), 8);
+    }
+
+    #[test]
+    fn test_unknown_field_values() {
+        // A different chip or a corrupted transfer must not decode as AT86RF215
+        let pn = RfPn::from_bits(0x42);
+        assert_eq!(pn.pn(), DevicePartNumber::Unknown(0x42));
+        assert!(pn.pn().is_unknown());
+
+        assert_eq!(
+            RfnState::from_bits(0x00).state(),
+            TransceiverState::Unknown(0)
+        );
+        assert_eq!(ChipResetCmd::from_bits(0x03), ChipResetCmd::Unknown(3));
+        assert_eq!(RfPn::reset().pn(), DevicePartNumber::AT86RF215);
+
+        // Unknown values are written back unchanged
+        let rxdfe = RfnRxdfe::new().with_sr(SampleRate::Unknown(7));
+        assert_eq!(rxdfe.sr(), SampleRate::Unknown(7));
+        assert_eq!(rxdfe.into_bits(), 0x07);
+    }
+
+    #[test]
+    fn test_register_display() {
+        use std::format;
+
+        let rxbwc = RfnRxbwc::new().with_bw(ReceiverBandwidth::Khz400);
+        assert_eq!(
+            format!("{rxbwc}"),
+            "RXBWC.BW = 4 (400kHz @ 500kHz IF), RXBWC.IFS = 0, RXBWC.IFI = 0"
+        );
+        assert_eq!(
+            format!("{rxbwc:#}"),
+            "RXBWC.BW = 4 (400kHz @ 500kHz IF)\nRXBWC.IFS = 0\nRXBWC.IFI = 0"
+        );
+
+        // Variants without a doc comment are shown by name
+        let state = RfnState::from_bits(0x02);
+        assert_eq!(format!("{state}"), "STATE.STATE = 2 (TrxOff)");
+        assert_eq!(
+            format!("{}", RfnState::from_bits(0x00)),
+            "STATE.STATE = 0 (unknown)"
+        );
+
+        let cmd = RfnCmd::new().with_cmd(TransceiverCmd::Rx);
+        assert_eq!(format!("{cmd}"), "CMD.CMD = 5 (Rx)");
+        assert_eq!(cmd.bits(), 5);
+
+        let rssi = RfnRssi::from_bits(0xB0);
+        assert_eq!(format!("{rssi}"), "RSSI.RSSI = -80");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_write_masks_read_only_fields() {
+        // FCSOK set by the chip stays out of a read-modify-write
+        let mut pc = ReadWrite::<BbcnPc, 0x0300, 1>::new(BbcnPc::reset());
+        pc.set_from_bytes(&[0x3D]);
+        assert!(pc.value.fcsok());
+        pc.value.set_ctx(true);
+        assert_eq!(pc.value_bytes(), [0x9D]);
+
+        let mut agcc = ReadWrite::<RfnAgcc, 0x010B, 1>::new(RfnAgcc::reset());
+        agcc.set_from_bytes(&[0x05]);
+        assert_eq!(agcc.value_bytes(), [0x01]);
+
+        let mut pmuc = ReadWrite::<BbcnPmuc, 0x0380, 1>::new(BbcnPmuc::reset());
+        pmuc.set_from_bytes(&[0x1D]);
+        assert_eq!(pmuc.value_bytes(), [0x01]);
+
+        assert_eq!(RfIqifc0::WRITABLE, 0xBF);
+        assert_eq!(RfIqifc1::WRITABLE, 0x73);
+        assert_eq!(RfnIrqs::WRITABLE, 0x00);
+        assert_eq!(RfnCmd::WRITABLE, 0x07);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_write_reserved_bits() {
+        // RFn_PLL bits 0 and 3 are reserved and written as after reset
+        assert_eq!(RfnPll::RESERVED, 0x09);
+        let pll = ReadWrite::<RfnPll, 0x0121, 1>::new(RfnPll::from_bits(0xFF));
+        assert_eq!(pll.value_bytes(), [0x39]);
+
+        // Reserved bits read back from the chip are not written
+        let cfg = ReadWrite::<RfCfg, 0x0006, 1>::new(RfCfg::from_bits(0xFF));
+        assert_eq!(cfg.value_bytes(), [0x0F]);
+    }
+
+    #[test]
+    fn test_write_command_into() {
+        let reg = ReadWrite::<RfCfg, 0x0006, 1>::new(RfCfg::from_bits(0x0B));
+
+        let mut buf = [0u8; 4];
+        assert_eq!(reg.write_command_into(&mut buf), Ok(3));
+        assert_eq!(buf, [0x80, 0x06, 0x0B, 0x00]);
+
+        let mut small = [0u8; 2];
+        assert_eq!(
+            reg.write_command_into(&mut small),
+            Err(BufferTooSmall {
+                required: 3,
+                available: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_read_command_heapless() {
+        let reg = ReadOnly::<BbcnCnt, 0x0390, 4>::new(BbcnCnt::new());
+
+        let cmd = reg.read_command_heapless();
+        assert_eq!(cmd.as_slice(), &[0x03, 0x90, 0x00, 0x00, 0x00, 0x00]);
+    }
+
+    #[test]
+    fn test_write_command_into_masks_value() {
+        // FCSOK read from the chip is not written back
+        let mut pc = ReadWrite::<BbcnPc, 0x0300, 1>::new(BbcnPc::reset());
+        pc.set_from_bytes(&[0x3D]);
+        pc.value.set_ctx(true);
+        let mut buf = [0u8; MAX_COMMAND_LEN];
+        assert_eq!(pc.write_command_into(&mut buf), Ok(3));
+        assert_eq!(buf[..3], [0x83, 0x00, 0x9D]);
+
+        // Reserved bits of RFn_PLL keep their reset value
+        let pll = ReadWrite::<RfnPll, 0x0121, 1>::new(RfnPll::from_bits(0xFF));
+        assert_eq!(pll.write_command_heapless().as_slice(), &[0x81, 0x21, 0x39]);
+    }
+
+    #[test]
+    fn test_bulk_reads_execute_split() {
+        let mut macea = ReadWrite::<BbcnMacea, 0x0325, 8>::new(BbcnMacea::new());
+        let mut macpid = ReadWrite::<BbcnMacpid, 0x032D, 2>::new(BbcnMacpid::new());
+        let mut macsha = ReadWrite::<BbcnMacsha, 0x032F, 2>::new(BbcnMacsha::new());
+
+        let mut reads = BulkReads::new();
+        reads.add(&mut macsha);
+        reads.add(&mut macea);
+        reads.add(&mut macpid);
+        reads.set_max_transfer_len(MAX_COMMAND_LEN);
+        assert_eq!(reads.max_command_len(), MAX_COMMAND_LEN);
+
+        let responses: [&[u8]; 2] = [
+            &[0, 0, 1, 2, 3, 4, 5, 6, 7, 8],
+            &[0, 0, 0x34, 0x12, 0x78, 0x56],
+        ];
+        let mut sent = 0;
+        let mut buf = [0u8; MAX_COMMAND_LEN];
+        reads
+            .execute(&mut buf, |cmd| {
+                let start = [[0x03, 0x25], [0x03, 0x2D]][sent];
+                assert_eq!(cmd[..HEADER_LEN], start, "command {sent}");
+                cmd.copy_from_slice(responses[sent]);
+                sent += 1;
+                Ok::<_, BufferTooSmall>(())
+            })
+            .unwrap();
+        assert_eq!(sent, 2);
+        drop(reads);
+
+        assert_eq!(macea.value.into_bits(), 0x0807_0605_0403_0201);
+        assert_eq!(macpid.value.into_bits(), 0x1234);
+        assert_eq!(macsha.value.into_bits(), 0x5678);
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    #[test]
+    fn test_bulk_capacity_without_alloc() {
+        let mut regs: [ReadWrite<RfnCs, 0x0104, 1>; MAX_BULK_REGISTERS + 1] =
+            core::array::from_fn(|_| ReadWrite::new(RfnCs::new()));
+        let (extra, regs) = regs.split_last_mut().unwrap();
+
+        let mut writes = BulkWrites::new();
+        for reg in regs.iter_mut() {
+            writes.try_add(reg).unwrap();
+        }
+        assert_eq!(writes.try_add(extra), Err(WriteError::Capacity));
+        assert_eq!(writes.len(), MAX_BULK_REGISTERS);
+        drop(writes);
+
+        let mut reads = BulkReads::new();
+        for reg in regs.iter_mut() {
+            reads.try_add(reg).unwrap();
+        }
+        assert_eq!(reads.try_add(extra), Err(CapacityError));
+
+        // Identical registers share one byte of the command
+        assert_eq!(reads.max_command_len(), HEADER_LEN + 1);
+    }
+
+    #[test]
+    fn test_bulk_reads_execute() {
+        let mut cfg = ReadWrite::<RfCfg, 0x0006, 1>::new(RfCfg::new());
+        let mut clko = ReadWrite::<RfClko, 0x0007, 1>::new(RfClko::new());
+
+        let mut reads = BulkReads::new();
+        reads.add(&mut clko);
+        reads.add(&mut cfg);
+        assert_eq!(reads.max_command_len(), 4);
+
+        let mut buf = [0u8; 4];
+        reads
+            .execute(&mut buf, |cmd| {
+                assert_eq!(cmd, &[0x00, 0x06, 0x00, 0x00]);
+                cmd.copy_from_slice(&[0x00, 0x00, 0x08, 0x09]);
+                Ok::<_, BufferTooSmall>(())
+            })
+            .unwrap();
+        drop(reads);
+
+        assert_eq!(cfg.value.into_bits(), 0x08);
+        assert_eq!(clko.value.into_bits(), 0x09);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_bulk_split_at_max_transfer_len() {
+        let mut macea = ReadWrite::<BbcnMacea, 0x0325, 8>::new(BbcnMacea::from_bits(u64::MAX));
+        let mut macpid = ReadWrite::<BbcnMacpid, 0x032D, 2>::new(BbcnMacpid::reset());
+        let mut macsha = ReadWrite::<BbcnMacsha, 0x032F, 2>::new(BbcnMacsha::reset());
+
+        let mut writes = BulkWrites::new();
+        writes.add(&mut macea);
+        writes.add(&mut macpid);
+        writes.add(&mut macsha);
+        assert_eq!(writes.max_command_len(), 14);
+
+        // Registers are never split, MACPID no longer fits after MACEA
+        writes.set_max_transfer_len(MAX_COMMAND_LEN);
+        assert_eq!(writes.max_command_len(), 10);
+        let starts: Vec<_> = writes
+            .get_blocks()
+            .iter()
+            .map(|block| (block.start_address, block.data.len()))
+            .collect();
+        assert_eq!(starts, [(0x0325, 8), (0x032D, 4)]);
+
+        let mut reads = BulkReads::new();
+        reads.add(&mut macea);
+        reads.add(&mut macpid);
+        reads.add(&mut macsha);
+        assert_eq!(reads.max_command_len(), 14);
+        assert_eq!(reads.max_command_len_within(12), 12);
+        reads.set_max_transfer_len(MAX_COMMAND_LEN);
+        assert_eq!(reads.max_command_len(), 10);
+        assert_eq!(reads.get_block_info(), [(0x0325, 8), (0x032D, 4)]);
+
+        // Each response is decoded into the registers of its own command
+        reads
+            .parse_responses(&[
+                vec![0, 0, 1, 2, 3, 4, 5, 6, 7, 8],
+                vec![0, 0, 0x34, 0x12, 0x78, 0x56],
+            ])
+            .unwrap();
+        drop(reads);
+        assert_eq!(macea.value.into_bits(), 0x0807_0605_0403_0201);
+        assert_eq!(macpid.value.into_bits(), 0x1234);
+        assert_eq!(macsha.value.into_bits(), 0x5678);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_bulk_reads_deduplicate() {
+        let mut cs = ReadWrite::<RfnCs, 0x0104, 1>::new(RfnCs::new());
+        let mut cs_dup = ReadWrite::<RfnCs, 0x0104, 1>::new(RfnCs::new());
+        let mut ccf0 = ReadWrite::<RfnCcf0, 0x0105, 2>::new(RfnCcf0::new());
+        let mut ccf0_high = ReadWrite::<RfnCnl, 0x0106, 1>::new(RfnCnl::new());
+        let mut cnl = ReadWrite::<RfnCnl, 0x0107, 1>::new(RfnCnl::new());
+
+        let mut reads = BulkReads::new();
+        reads.add(&mut cnl);
+        reads.add(&mut ccf0_high);
+        reads.add(&mut cs);
+        reads.add(&mut ccf0);
+        reads.add(&mut cs_dup);
+
+        // Every byte is read once, overlapping registers share it
+        assert_eq!(reads.get_block_info(), [(0x0104, 4)]);
+        reads
+            .parse_responses(&[vec![0, 0, 0x30, 0xA0, 0x8C, 0x05]])
+            .unwrap();
+        drop(reads);
+        assert_eq!(cs.value.into_bits(), 0x30);
+        assert_eq!(cs_dup.value.into_bits(), 0x30);
+        assert_eq!(ccf0.value.into_bits(), 0x8CA0);
+        assert_eq!(ccf0_high.value.into_bits(), 0x8C);
+        assert_eq!(cnl.value.into_bits(), 0x05);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_bulk_writes_reject_conflicts() {
+        let mut cmd =
+            WriteOnly::<RfnCmd, 0x0103, 1>::new(RfnCmd::new().with_cmd(TransceiverCmd::Rx));
+        let mut cmd_dup =
+            WriteOnly::<RfnCmd, 0x0103, 1>::new(RfnCmd::new().with_cmd(TransceiverCmd::Rx));
+        let mut cs = ReadWrite::<RfnCs, 0x0104, 1>::new(RfnCs::from_bits(0x30));
+        let mut cs_other = ReadWrite::<RfnCs, 0x0104, 1>::new(RfnCs::from_bits(0x31));
+        let mut ccf0 = ReadWrite::<RfnCcf0, 0x0105, 2>::new(RfnCcf0::from_bits(0x8CA0));
+        let mut ccf0_high = ReadWrite::<RfnCnl, 0x0106, 1>::new(RfnCnl::from_bits(0x8C));
+        let mut ccf0_other = ReadWrite::<RfnCnl, 0x0106, 1>::new(RfnCnl::from_bits(0x8D));
+
+        let mut writes = BulkWrites::new();
+        writes.add(&mut cmd);
+        writes.add(&mut cmd_dup);
+        writes.add(&mut cs);
+        writes.add(&mut ccf0);
+        writes.add(&mut ccf0_high);
+        assert_eq!(
+            writes.try_add(&mut cs_other),
+            Err(WriteError::Conflict {
+                address: 0x0104,
+                existing: 0x0104
+            })
+        );
+        assert_eq!(
+            writes.try_add(&mut ccf0_other),
+            Err(WriteError::Conflict {
+                address: 0x0106,
+                existing: 0x0105
+            })
+        );
+
+        // Identical registers are written once, the command is not repeated
+        assert_eq!(
+            writes.generate_commands(),
+            [vec![0x81, 0x04, 0x30, 0xA0, 0x8C], vec![0x81, 0x03, 0x05]]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "BulkWrites register rejected")]
+    fn test_bulk_writes_add_panics_on_conflict() {
+        let mut cs = ReadWrite::<RfnCs, 0x0104, 1>::new(RfnCs::from_bits(0x30));
+        let mut cs_other = ReadWrite::<RfnCs, 0x0104, 1>::new(RfnCs::from_bits(0x31));
+
+        let mut writes = BulkWrites::new();
+        writes.add(&mut cs);
+        writes.add(&mut cs_other);
+    }
+
+    #[test]
+    fn test_bulk_writes_channel_change() {
+        let mut cmd =
+            WriteOnly::<RfnCmd, 0x0103, 1>::new(RfnCmd::new().with_cmd(TransceiverCmd::Rx));
+        let mut cs = ReadWrite::<RfnCs, 0x0104, 1>::new(RfnCs::from_bits(0x30));
+        let mut ccf0 = ReadWrite::<RfnCcf0, 0x0105, 2>::new(RfnCcf0::from_bits(0x8CA0));
+        let mut cnl = ReadWrite::<RfnCnl, 0x0107, 1>::new(RfnCnl::from_bits(0x05));
+        let mut cnm = ReadWrite::<RfnCnm, 0x0108, 1>::new(RfnCnm::new());
+        let mut rxbwc = ReadWrite::<RfnRxbwc, 0x0109, 1>::new(RfnRxbwc::reset());
+        let mut cnl24 = ReadWrite::<RfnCnl, 0x0207, 1>::new(RfnCnl::from_bits(0x10));
+        let mut cnm24 = ReadWrite::<RfnCnm, 0x0208, 1>::new(RfnCnm::new());
+
+        let mut writes = BulkWrites::new();
+        writes.add(&mut cnm24);
+        writes.add(&mut cmd);
+        writes.add(&mut cnm);
+        writes.add(&mut rxbwc);
+        writes.add(&mut cnl);
+        writes.add(&mut ccf0);
+        writes.add(&mut cnl24);
+        writes.add(&mut cs);
+
+        // CS..CNM stay one burst ending in CNM, RXBWC after CNM is split off
+        // and written first, the command goes last
+        let expected: [&[u8]; 4] = [
+            &[0x81, 0x09, 0x09],
+            &[0x81, 0x04, 0x30, 0xA0, 0x8C, 0x05, 0x00],
+            &[0x82, 0x07, 0x10, 0x00],
+            &[0x81, 0x03, 0x05],
+        ];
+        let mut sent = 0;
+        let mut buf = [0u8; 8];
+        writes
+            .execute(&mut buf, |cmd| {
+                assert_eq!(cmd, expected[sent], "command {sent}");
+                sent += 1;
+                Ok::<_, BufferTooSmall>(())
+            })
+            .unwrap();
+        assert_eq!(sent, expected.len());
+        assert_eq!(writes.max_command_len(), 7);
+    }
+
+    #[test]
+    fn test_write_stages() {
+        let cnm = ReadWrite::<RfnCnm, 0x0108, 1>::new(RfnCnm::new());
+        let cmd = WriteOnly::<RfnCmd, 0x0103, 1>::new(RfnCmd::new());
+        let rst = WriteOnly::<RfRst, 0x0005, 1>::new(RfRst::new());
+        let cs = ReadWrite::<RfnCs, 0x0104, 1>::new(RfnCs::new());
+
+        assert_eq!(cs.write_stage(), WriteStage::Config);
+        assert_eq!(cnm.write_stage(), WriteStage::Commit);
+        assert_eq!(cmd.write_stage(), WriteStage::Command);
+        assert_eq!(rst.write_stage(), WriteStage::Command);
+    }
+
+    #[test]
+    fn test_side_effect_metadata() {
+        let irqs = ReadOnly::<RfnIrqs, 0x0000, 1>::new(RfnIrqs::new());
+        let bbc_irqs = ReadOnly::<BbcnIrqs, 0x0002, 1>::new(BbcnIrqs::new());
+        let state = ReadOnly::<RfnState, 0x0102, 1>::new(RfnState::reset());
+        assert!(irqs.clears_on_read());
+        assert!(bbc_irqs.clears_on_read());
+        assert!(!state.clears_on_read());
+
+        let mut cmd = WriteOnly::<RfnCmd, 0x0103, 1>::new(RfnCmd::new());
+        assert!(!cmd.triggers());
+        cmd.value.set_cmd(TransceiverCmd::Rx);
+        assert!(cmd.triggers());
+
+        let rst = WriteOnly::<RfRst, 0x0005, 1>::new(RfRst::new().with_cmd(ChipResetCmd::Reset));
+        assert!(rst.triggers());
+
+        let mut agcc = ReadWrite::<RfnAgcc, 0x010B, 1>::new(RfnAgcc::reset());
+        assert!(!agcc.triggers());
+        agcc.value.set_rst(true);
+        assert!(agcc.triggers());
+    }
+
+    #[test]
+    fn test_bulk_reads_isolate_clear_on_read() {
+        let mut rf09 = ReadOnly::<RfnIrqs, 0x0000, 1>::new(RfnIrqs::new());
+        let mut rf24 = ReadOnly::<RfnIrqs, 0x0001, 1>::new(RfnIrqs::new());
+        let mut cfg = ReadWrite::<RfCfg, 0x0002, 1>::new(RfCfg::new());
+
+        let mut reads = BulkReads::new();
+        reads.add(&mut rf09);
+        reads.add(&mut rf24);
+        reads.add(&mut cfg);
+        let mut count = 0;
+        let mut buf = [0u8; 5];
+        reads
+            .execute(&mut buf, |cmd| {
+                assert_eq!(cmd.len(), 3);
+                count += 1;
+                Ok::<_, BufferTooSmall>(())
+            })
+            .unwrap();
+        assert_eq!(count, 3);
+
+        // Opting in reads all three in one block
+        reads.coalesce_side_effects(true);
+        assert_eq!(reads.max_command_len(), 5);
+    }
+
+    #[test]
+    fn test_bulk_writes_isolate_triggers() {
+        let mut rxdfe = ReadWrite::<RfnRxdfe, 0x010A, 1>::new(RfnRxdfe::reset());
+        let mut agcc = ReadWrite::<RfnAgcc, 0x010B, 1>::new(RfnAgcc::reset());
+        agcc.value.set_rst(true);
+
+        let mut writes = BulkWrites::new();
+        writes.add(&mut rxdfe);
+        writes.add(&mut agcc);
+        let expected: [&[u8]; 2] = [&[0x81, 0x0A, 0x01], &[0x81, 0x0B, 0x09]];
+        let mut sent = 0;
+        let mut buf = [0u8; 4];
+        writes
+            .execute(&mut buf, |cmd| {
+                assert_eq!(cmd, expected[sent]);
+                sent += 1;
+                Ok::<_, BufferTooSmall>(())
+            })
+            .unwrap();
+        assert_eq!(sent, 2);
+
+        writes.coalesce_side_effects(true);
+        assert_eq!(writes.max_command_len(), 4);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_parse_responses_reports_stale_registers() {
+        let mut cfg = ReadWrite::<RfCfg, 0x0006, 1>::new(RfCfg::new());
+        let mut clko = ReadWrite::<RfClko, 0x0007, 1>::new(RfClko::new());
+        let mut state = ReadOnly::<RfnState, 0x0102, 1>::new(RfnState::new());
+        let mut reads = BulkReads::new();
+        reads.add(&mut cfg);
+        reads.add(&mut clko);
+        reads.add(&mut state);
+
+        // Missing response for the second command
+        let err = reads
+            .parse_responses(&[vec![0, 0, 0x01, 0x02]])
+            .unwrap_err();
+        assert_eq!((err.expected, err.received), (2, 1));
+        assert_eq!(
+            err.stale,
+            [StaleRegister {
+                address: 0x0102,
+                reason: StaleReason::MissingResponse
+            }]
+        );
+
+        // RF_CLKO cut off, the other registers are still updated
+        let err = reads
+            .parse_responses(&[vec![0, 0, 0x03], vec![0, 0, 0x05]])
+            .unwrap_err();
+        assert_eq!(
+            err.stale,
+            [StaleRegister {
+                address: 0x0007,
+                reason: StaleReason::ShortResponse {
+                    len: 3,
+                    expected: 4
+                }
+            }]
+        );
+
+        // More responses than commands
+        let responses = [vec![0, 0, 0x0F, 0x09], vec![0, 0, 0x02], vec![0, 0]];
+        let err = reads.parse_responses(&responses).unwrap_err();
+        assert_eq!((err.expected, err.received), (2, 3));
+        assert!(err.stale.is_empty());
+        assert_eq!(reads.parse_responses(&responses[..2]), Ok(()));
+
+        // Surplus responses fail the batch too
+        let responses = [vec![0, 0, 0x03, 0x0C], vec![0, 0, 0x04], vec![0, 0]];
+        let err = reads.parse_responses_strict(&responses).unwrap_err();
+        assert_eq!((err.expected, err.received), (2, 3));
+        assert_eq!(err.stale.len(), 3);
+        assert!(
+            err.stale
+                .iter()
+                .all(|stale| stale.reason == StaleReason::BatchFailed)
+        );
+
+        // Strict mode leaves every register untouched
+        let err = reads
+            .parse_responses_strict(&[vec![0, 0, 0x0B, 0x0A], vec![0, 0]])
+            .unwrap_err();
+        let reasons: Vec<_> = err.stale.iter().map(|stale| stale.reason).collect();
+        assert_eq!(
+            reasons,
+            [
+                StaleReason::BatchFailed,
+                StaleReason::BatchFailed,
+                StaleReason::ShortResponse {
+                    len: 2,
+                    expected: 3
+                },
+            ]
+        );
+        drop(reads);
+        assert_eq!(cfg.value.into_bits(), 0x0F);
+        assert_eq!(clko.value.into_bits(), 0x09);
+        assert_eq!(state.value.into_bits(), 0x02);
+    }
+
+    #[test]
+    fn test_const_command_arrays() {
+        type Rf09Cmd = ReadWrite<RfnCmd, 0x0103, 1>;
+        type Bbc0Cnt = ReadOnly<BbcnCnt, 0x0391, 4>;
+
+        static TRXOFF: [u8; 3] = Rf09Cmd::write_command_array(
+            RfnCmd::new().with_cmd(TransceiverCmd::TrxOff).into_bits(),
+        );
+        static READ_CNT: [u8; 6] = Bbc0Cnt::READ_COMMAND;
+
+        assert_eq!(TRXOFF, [0x81, 0x03, 0x02]);
+        assert_eq!(READ_CNT, [0x03, 0x91, 0x00, 0x00, 0x00, 0x00]);
+
+        // Same bytes as the runtime built commands
+        let mut cmd = Rf09Cmd::new(RfnCmd::new().with_cmd(TransceiverCmd::TrxOff));
+        assert_eq!(cmd.write_command_heapless().as_slice(), &TRXOFF);
+        cmd.value.set_cmd(TransceiverCmd::Tx);
+        assert_eq!(
+            cmd.read_command_heapless().as_slice(),
+            &Rf09Cmd::READ_COMMAND
+        );
+
+        let ccf0 = ReadWrite::<RfnCcf0, 0x0105, 2>::write_command_array(0x1234);
+        assert_eq!(ccf0, [0x81, 0x05, 0x34, 0x12]);
+
+        // Read-only and reserved bits are masked as in the runtime commands
+        type Rf09Pll = ReadWrite<RfnPll, 0x0121, 1>;
+        static PLL: [u8; 3] = Rf09Pll::write_command_array(0xFF);
+        let pll = Rf09Pll::new(RfnPll::from_bits(0xFF));
+        assert_eq!(PLL, [0x81, 0x21, 0x39]);
+        assert_eq!(pll.write_command_heapless().as_slice(), &PLL);
+
+        let pc = ReadWrite::<BbcnPc, 0x0301, 1>::write_command_array(0xFF);
+        assert_eq!(pc, [0x83, 0x01, 0xDF]);
+        let cmd = WriteOnly::<RfnCmd, 0x0103, 1>::write_command_array(0xFF);
+        assert_eq!(cmd, [0x81, 0x03, 0x07]);
+    }
 }
@@ -0,0 +1,669 @@
+//! AT86RF215 Simulator
+//!
+//! A software model of the chip's SPI protocol for testing without hardware.
+//! [`Simulator`] implements `embedded_hal::spi::SpiDevice`, so it can be
+//! passed to [`Driver`](crate::driver::Driver) like a real SPI device.
+//!
+//! The simulator holds the full 0x0000-0x3FFF memory image (registers and
+//! frame buffers) and models:
+//! - Block read/write with address auto-increment
+//! - The RFn_CMD to RFn_STATE state machine, including TRANSITION delays
+//! - Read-to-clear IRQS registers
+//! - Frame transmission from the TX frame buffer using BBCn_TXFL
+//! - Frame reception into the RX frame buffer, updating BBCn_RXFL
+//!
+//! Time is counted in ticks, one tick per SPI transaction. Use
+//! [`Simulator::advance`] to let time pass without bus traffic.
+
+use alloc::vec::Vec;
+use core::convert::Infallible;
+
+use embedded_hal::spi::{ErrorType, Operation, SpiDevice};
+
+use crate::radio::{
+    BBC0_FBRXS, BBC0_FBTXS, BBC1_FBRXS, BBC1_FBTXS, MAX_FRAME_LEN, REGISTER_MAP, Radio,
+};
+use crate::registers::{ADDRESS_SPACE_LEN, DevicePartNumber, TransceiverCmd, TransceiverState};
+
+/// Size of the SPI address space
+pub const MEMORY_SIZE: usize = ADDRESS_SPACE_LEN;
+
+const RF_IRQS: [u16; 2] = [0x0000, 0x0001];
+const BBC_IRQS: [u16; 2] = [0x0002, 0x0003];
+const RF_RST: u16 = 0x0005;
+const RF_PN: u16 = 0x000D;
+const RF_VN: u16 = 0x000E;
+
+const RF_BASE: [u16; 2] = [0x0100, 0x0200];
+const BBC_BASE: [u16; 2] = [0x0300, 0x0400];
+const FB_TX: [u16; 2] = [BBC0_FBTXS, BBC1_FBTXS];
+const FB_RX: [u16; 2] = [BBC0_FBRXS, BBC1_FBRXS];
+
+// Offsets within the RFn and BBCn blocks
+const RF_STATE: u16 = 0x02;
+const RF_CMD: u16 = 0x03;
+const BBC_RXFL: u16 = 0x04;
+const BBC_TXFL: u16 = 0x06;
+
+// IRQS bits
+const RF_IRQ_WAKEUP: u8 = 1 << 0;
+const RF_IRQ_TRXRDY: u8 = 1 << 1;
+const BBC_IRQ_RXFS: u8 = 1 << 0;
+const BBC_IRQ_RXFE: u8 = 1 << 1;
+const BBC_IRQ_TXFE: u8 = 1 << 4;
+
+/// Simulator timing configuration
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SimConfig {
+    /// Ticks spent in TRANSITION after a state change command
+    pub transition_delay: u32,
+
+    /// Ticks spent in TX before the frame end interrupt
+    pub tx_delay: u32,
+
+    /// Reported device version number
+    pub version: u8,
+
+    /// Whether the transceivers report WAKEUP once reset
+    pub wakeup: bool,
+}
+
+impl Default for SimConfig {
+    fn default() -> Self {
+        Self {
+            transition_delay: 2,
+            tx_delay: 4,
+            version: 0x03,
+            wakeup: true,
+        }
+    }
+}
+
+/// State of one simulated transceiver (RF09 or RF24)
+#[derive(Debug, Clone, Copy)]
+struct Transceiver {
+    state: TransceiverState,
+
+    /// State reached once the pending transition or transmission completes
+    target: TransceiverState,
+    remaining: u32,
+}
+
+impl Transceiver {
+    const fn new() -> Self {
+        Self {
+            state: TransceiverState::TrxOff,
+            target: TransceiverState::TrxOff,
+            remaining: 0,
+        }
+    }
+}
+
+/// Phase of the SPI protocol within a transaction
+#[derive(Debug, Clone, Copy)]
+enum Phase {
+    Header0,
+    Header1(u8),
+    Read(u16),
+    Write(u16),
+}
+
+/// Simulated AT86RF215
+pub struct Simulator {
+    mem: [u8; MEMORY_SIZE],
+    rf: [Transceiver; 2],
+    config: SimConfig,
+
+    /// Frames sent by each baseband, oldest first
+    transmitted: [Vec<Vec<u8>>; 2],
+}
+
+impl Default for Simulator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Simulator {
+    /// Creates a simulator in the state after power-on.
+    pub fn new() -> Self {
+        Self::with_config(SimConfig::default())
+    }
+
+    pub fn with_config(config: SimConfig) -> Self {
+        let mut sim = Self {
+            mem: [0x00; MEMORY_SIZE],
+            rf: [Transceiver::new(); 2],
+            config,
+            transmitted: [Vec::new(), Vec::new()],
+        };
+        sim.reset();
+        sim
+    }
+
+    /// Performs a chip reset, as triggered by RF_RST or power-on.
+    ///
+    /// Registers take the values of [`Radio::reset_defaults`], frame buffers
+    /// are cleared.
+    pub fn reset(&mut self) {
+        self.mem = [0x00; MEMORY_SIZE];
+        for (info, reg) in REGISTER_MAP.iter().zip(Radio::reset_defaults().registers()) {
+            let start = info.address as usize;
+            let bytes = reg.value().bits().to_le_bytes();
+            self.mem[start..start + info.size].copy_from_slice(&bytes[..info.size]);
+        }
+        self.mem[RF_PN as usize] = DevicePartNumber::AT86RF215.into_bits();
+        self.mem[RF_VN as usize] = self.config.version;
+
+        for (band, irqs) in RF_IRQS.into_iter().enumerate() {
+            self.rf[band] = Transceiver::new();
+            if self.config.wakeup {
+                self.mem[irqs as usize] = RF_IRQ_WAKEUP;
+            }
+            self.sync_state(band);
+        }
+    }
+
+    /// Reads a byte from the memory image without side effects.
+    pub fn peek(&self, addr: u16) -> u8 {
+        self.mem[(addr & 0x3FFF) as usize]
+    }
+
+    /// Writes a byte to the memory image without side effects.
+    pub fn poke(&mut self, addr: u16, value: u8) {
+        self.mem[(addr & 0x3FFF) as usize] = value;
+    }
+
+    /// Current state of a transceiver, 0 for RF09 and 1 for RF24.
+    pub fn state(&self, band: usize) -> TransceiverState {
+        self.rf[band].state
+    }
+
+    /// Removes and returns the frames transmitted by a baseband, 0 for BBC0 and 1 for BBC1.
+    pub fn take_transmitted(&mut self, baseband: usize) -> Vec<Vec<u8>> {
+        core::mem::take(&mut self.transmitted[baseband])
+    }
+
+    /// Delivers a frame to a baseband, 0 for BBC0 and 1 for BBC1.
+    ///
+    /// The frame is only received while the transceiver is in RX. It is
+    /// copied to the RX frame buffer, BBCn_RXFL is updated and the RXFS and
+    /// RXFE interrupts are raised.
+    ///
+    /// Returns `true` if the frame was received.
+    pub fn receive_frame(&mut self, baseband: usize, frame: &[u8]) -> bool {
+        if self.rf[baseband].state != TransceiverState::Rx || frame.len() > MAX_FRAME_LEN {
+            return false;
+        }
+
+        let start = FB_RX[baseband] as usize;
+        self.mem[start..start + frame.len()].copy_from_slice(frame);
+
+        let rxfl = BBC_BASE[baseband] + BBC_RXFL;
+        let len = (frame.len() as u16).to_le_bytes();
+        self.mem[rxfl as usize] = len[0];
+        self.mem[rxfl as usize + 1] = len[1];
+
+        self.mem[BBC_IRQS[baseband] as usize] |= BBC_IRQ_RXFS | BBC_IRQ_RXFE;
+        true
+    }
+
+    /// Lets `ticks` of time pass.
+    pub fn advance(&mut self, ticks: u32) {
+        for _ in 0..ticks {
+            self.tick();
+        }
+    }
+
+    fn tick(&mut self) {
+        for band in 0..self.rf.len() {
+            let rf = &mut self.rf[band];
+            if rf.remaining == 0 {
+                continue;
+            }
+
+            rf.remaining -= 1;
+            if rf.remaining == 0 {
+                self.complete(band);
+            }
+        }
+    }
+
+    /// Completes the pending transition or transmission of `band`.
+    fn complete(&mut self, band: usize) {
+        let rf = &self.rf[band];
+        match (rf.state, rf.target) {
+            (TransceiverState::Tx, _) => self.finish_tx(band),
+            (_, TransceiverState::TxPrep) => {
+                self.rf[band].state = TransceiverState::TxPrep;
+                self.mem[RF_IRQS[band] as usize] |= RF_IRQ_TRXRDY;
+            }
+            (_, TransceiverState::Tx) => self.start_tx(band),
+            (_, target) => self.rf[band].state = target,
+        }
+        self.sync_state(band);
+    }
+
+    fn start_tx(&mut self, band: usize) {
+        let rf = &mut self.rf[band];
+        rf.state = TransceiverState::Tx;
+        rf.target = TransceiverState::TxPrep;
+        rf.remaining = self.config.tx_delay.max(1);
+    }
+
+    fn finish_tx(&mut self, band: usize) {
+        let txfl = (BBC_BASE[band] + BBC_TXFL) as usize;
+        let len = u16::from_le_bytes([self.mem[txfl], self.mem[txfl + 1]]) as usize & 0x07FF;
+
+        let start = FB_TX[band] as usize;
+        self.transmitted[band].push(self.mem[start..start + len].to_vec());
+
+        // The transceiver returns to TXPREP after the frame is sent
+        self.rf[band].state = TransceiverState::TxPrep;
+        self.mem[BBC_IRQS[band] as usize] |= BBC_IRQ_TXFE;
+    }
+
+    fn command(&mut self, band: usize, cmd: TransceiverCmd) {
+        let target = match cmd {
+            TransceiverCmd::Nop | TransceiverCmd::Unknown(_) => return,
+            TransceiverCmd::Sleep => TransceiverState::Reset,
+            TransceiverCmd::TrxOff => TransceiverState::TrxOff,
+            TransceiverCmd::TxPrep => TransceiverState::TxPrep,
+            TransceiverCmd::Rx => TransceiverState::Rx,
+            TransceiverCmd::Tx => {
+                // TX is only accepted from TXPREP
+                if self.rf[band].state != TransceiverState::TxPrep {
+                    return;
+                }
+                TransceiverState::Tx
+            }
+            TransceiverCmd::Reset => {
+                self.rf[band] = Transceiver::new();
+                if self.config.wakeup {
+                    self.mem[RF_IRQS[band] as usize] |= RF_IRQ_WAKEUP;
+                }
+                self.sync_state(band);
+                return;
+            }
+        };
+
+        let delay = self.config.transition_delay;
+        let rf = &mut self.rf[band];
+        rf.state = TransceiverState::Transition;
+        rf.target = target;
+        rf.remaining = delay;
+        if delay == 0 {
+            // Only this transceiver changes state, the other keeps its timing
+            self.complete(band);
+        } else {
+            self.sync_state(band);
+        }
+    }
+
+    fn sync_state(&mut self, band: usize) {
+        let addr = (RF_BASE[band] + RF_STATE) as usize;
+        self.mem[addr] = self.rf[band].state.into_bits();
+    }
+
+    fn read_byte(&mut self, addr: u16) -> u8 {
+        let value = self.mem[addr as usize];
+        if RF_IRQS.contains(&addr) || BBC_IRQS.contains(&addr) {
+            self.mem[addr as usize] = 0x00;
+        }
+        value
+    }
+
+    fn write_byte(&mut self, addr: u16, value: u8) {
+        match addr {
+            _ if RF_IRQS.contains(&addr) || BBC_IRQS.contains(&addr) => {}
+            RF_PN | RF_VN => {}
+            RF_RST => {
+                if value & 0x07 == 0x07 {
+                    self.reset();
+                }
+            }
+            _ => {
+                if RF_BASE.iter().any(|base| addr == base + RF_STATE) {
+                    return;
+                }
+
+                self.mem[addr as usize] = value;
+                if let Some(band) = RF_BASE.iter().position(|base| addr == base + RF_CMD) {
+                    self.command(band, TransceiverCmd::from_bits(value & 0x07));
+                }
+            }
+        }
+    }
+
+    /// Clocks one byte through the SPI protocol and returns the MISO byte.
+    fn clock(&mut self, phase: &mut Phase, mosi: u8) -> u8 {
+        match *phase {
+            Phase::Header0 => {
+                *phase = Phase::Header1(mosi);
+                0x00
+            }
+            Phase::Header1(high) => {
+                let header = u16::from_be_bytes([high, mosi]);
+                let addr = header & 0x3FFF;
+                *phase = match header >> 14 {
+                    0b10 => Phase::Write(addr),
+                    _ => Phase::Read(addr),
+                };
+                0x00
+            }
+            Phase::Read(addr) => {
+                *phase = Phase::Read((addr + 1) & 0x3FFF);
+                self.read_byte(addr)
+            }
+            Phase::Write(addr) => {
+                *phase = Phase::Write((addr + 1) & 0x3FFF);
+                self.write_byte(addr, mosi);
+                0x00
+            }
+        }
+    }
+
+    fn run(&mut self, operations: &mut [Operation<'_, u8>]) {
+        let mut phase = Phase::Header0;
+
+        for op in operations.iter_mut() {
+            match op {
+                Operation::Read(buf) => {
+                    for byte in buf.iter_mut() {
+                        *byte = self.clock(&mut phase, 0x00);
+                    }
+                }
+                Operation::Write(buf) => {
+                    for byte in buf.iter() {
+                        self.clock(&mut phase, *byte);
+                    }
+                }
+                Operation::Transfer(read, write) => {
+                    for i in 0..read.len().max(write.len()) {
+                        let miso = self.clock(&mut phase, write.get(i).copied().unwrap_or(0x00));
+                        if let Some(byte) = read.get_mut(i) {
+                            *byte = miso;
+                        }
+                    }
+                }
+                Operation::TransferInPlace(buf) => {
+                    for byte in buf.iter_mut() {
+                        *byte = self.clock(&mut phase, *byte);
+                    }
+                }
+                Operation::DelayNs(_) => {}
+            }
+        }
+
+        self.tick();
+    }
+}
+
+impl ErrorType for Simulator {
+    type Error = Infallible;
+}
+
+impl SpiDevice for Simulator {
+    fn transaction(&mut self, operations: &mut [Operation<'_, u8>]) -> Result<(), Self::Error> {
+        self.run(operations);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "async")]
+impl embedded_hal_async::spi::SpiDevice for Simulator {
+    async fn transaction(
+        &mut self,
+        operations: &mut [Operation<'_, u8>],
+    ) -> Result<(), Self::Error> {
+        self.run(operations);
+        Ok(())
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::driver::{Driver, Error, InitConfig};
+    use crate::radio::{Band, Radio};
+    use crate::registers::*;
+    use alloc::vec;
+    use embedded_hal_mock::eh1::delay::NoopDelay;
+
+    #[test]
+    fn test_block_auto_increment() {
+        let mut sim = Simulator::new();
+
+        sim.write(&[0x80, 0x06, 0x0B, 0x09]).unwrap();
+        assert_eq!(sim.peek(0x0006), 0x0B);
+        assert_eq!(sim.peek(0x0007), 0x09);
+
+        let mut buf = [0x00, 0x0D, 0x00, 0x00];
+        sim.transfer_in_place(&mut buf).unwrap();
+        assert_eq!(buf, [0x00, 0x00, 0x34, 0x03]);
+    }
+
+    #[test]
+    fn test_irqs_read_to_clear() {
+        let mut driver = Driver::new(Simulator::new());
+        let mut irqs = ReadOnly::<RfnIrqs, 0x0000, 1>::new(RfnIrqs::new());
+
+        driver.read(&mut irqs).unwrap();
+        assert!(irqs.value.wakeup());
+
+        driver.read(&mut irqs).unwrap();
+        assert!(!irqs.value.wakeup());
+    }
+
+    #[test]
+    fn test_state_transition_delay() {
+        let config = SimConfig {
+            transition_delay: 3,
+            ..Default::default()
+        };
+        let mut driver = Driver::new(Simulator::with_config(config));
+        let mut radio = Radio::new();
+
+        radio.rf09.cmd.value.set_cmd(TransceiverCmd::TxPrep);
+        driver.write(&radio.rf09.cmd).unwrap();
+
+        driver.read(&mut radio.rf09.state).unwrap();
+        assert_eq!(radio.rf09.state.value.state(), TransceiverState::Transition);
+        driver.read(&mut radio.rf09.state).unwrap();
+        assert_eq!(radio.rf09.state.value.state(), TransceiverState::Transition);
+        driver.read(&mut radio.rf09.state).unwrap();
+        assert_eq!(radio.rf09.state.value.state(), TransceiverState::TxPrep);
+
+        // RF24 is unaffected
+        assert_eq!(driver.release().state(1), TransceiverState::TrxOff);
+    }
+
+    #[test]
+    fn test_immediate_command_leaves_other_band() {
+        let config = SimConfig {
+            transition_delay: 0,
+            tx_delay: 4,
+            ..Default::default()
+        };
+        let mut sim = Simulator::with_config(config);
+
+        // RF09 reaches TXPREP at once and starts a 4 tick transmission
+        sim.write(&[0x83, 0x06, 0x01, 0x00]).unwrap();
+        sim.write(&[0x81, 0x03, TransceiverCmd::TxPrep.into_bits()])
+            .unwrap();
+        assert_eq!(sim.state(0), TransceiverState::TxPrep);
+        sim.write(&[0x81, 0x03, TransceiverCmd::Tx.into_bits()])
+            .unwrap();
+        assert_eq!(sim.state(0), TransceiverState::Tx);
+
+        // A command on RF24 completes at once without advancing RF09
+        sim.write(&[0x82, 0x03, TransceiverCmd::TxPrep.into_bits()])
+            .unwrap();
+        assert_eq!(sim.state(1), TransceiverState::TxPrep);
+        assert_eq!(sim.state(0), TransceiverState::Tx);
+
+        // The two transactions since TX took one tick each
+        sim.advance(1);
+        assert_eq!(sim.state(0), TransceiverState::Tx);
+        sim.advance(1);
+        assert_eq!(sim.state(0), TransceiverState::TxPrep);
+        assert_eq!(sim.take_transmitted(0).len(), 1);
+
+        // Each transceiver raised TRXRDY
+        let mut irqs = [0x00, 0x00, 0x00, 0x00];
+        sim.transfer_in_place(&mut irqs).unwrap();
+        assert_eq!(irqs[2..], [RF_IRQ_WAKEUP | RF_IRQ_TRXRDY; 2]);
+    }
+
+    #[test]
+    fn test_transmit_frame() {
+        let mut driver = Driver::new(Simulator::new());
+        let mut radio = Radio::new();
+        let mut irqs = ReadOnly::<BbcnIrqs, 0x0002, 1>::new(BbcnIrqs::new());
+
+        driver
+            .write_buffer(BBC0_FBTXS, &[0xDE, 0xAD, 0xBE, 0xEF])
+            .unwrap();
+        radio.bbc0.txfl.value.set_txfl(3);
+        driver.write(&radio.bbc0.txfl).unwrap();
+
+        radio.rf09.cmd.value.set_cmd(TransceiverCmd::TxPrep);
+        driver.write(&radio.rf09.cmd).unwrap();
+        let mut sim = driver.release();
+        sim.advance(2);
+        assert_eq!(sim.state(0), TransceiverState::TxPrep);
+
+        let mut driver = Driver::new(sim);
+        radio.rf09.cmd.value.set_cmd(TransceiverCmd::Tx);
+        driver.write(&radio.rf09.cmd).unwrap();
+
+        let mut sim = driver.release();
+        sim.advance(10);
+        assert_eq!(sim.state(0), TransceiverState::TxPrep);
+        assert_eq!(sim.take_transmitted(0), vec![vec![0xDE, 0xAD, 0xBE]]);
+
+        let mut driver = Driver::new(sim);
+        driver.read(&mut irqs).unwrap();
+        assert!(irqs.value.txfe());
+    }
+
+    #[test]
+    fn test_receive_frame() {
+        let mut sim = Simulator::new();
+        assert!(!sim.receive_frame(1, &[0x01, 0x02]));
+
+        sim.write(&[0x82, 0x03, TransceiverCmd::Rx.into_bits()])
+            .unwrap();
+        sim.advance(2);
+        assert!(sim.receive_frame(1, &[0x01, 0x02]));
+
+        let mut driver = Driver::new(sim);
+        let mut radio = Radio::new();
+        driver.read(&mut radio.bbc1.rxfl).unwrap();
+        assert_eq!(radio.bbc1.rxfl.value.rxfl(), 2);
+
+        let mut frame = [0u8; 2];
+        driver.read_buffer(BBC1_FBRXS, &mut frame).unwrap();
+        assert_eq!(frame, [0x01, 0x02]);
+    }
+
+    #[test]
+    fn test_frame_api() {
+        let mut sim = Simulator::new();
+        sim.write(&[0x82, 0x03, TransceiverCmd::Rx.into_bits()])
+            .unwrap();
+        sim.advance(2);
+        assert!(sim.receive_frame(1, &[0x10, 0x20, 0x30]));
+
+        let mut driver = Driver::new(sim);
+        let mut buf = [0u8; MAX_FRAME_LEN];
+        let len = driver.read_frame(Band::Rf24, &mut buf).unwrap();
+        assert_eq!(&buf[..len], &[0x10, 0x20, 0x30]);
+
+        driver.write_frame(Band::Rf24, &[0x40, 0x50]).unwrap();
+        let sim = driver.release();
+        assert_eq!(sim.peek(BBC1_FBTXS), 0x40);
+        assert_eq!(sim.peek(BBC1_FBTXS + 1), 0x50);
+        assert_eq!(sim.peek(0x0406), 0x02);
+    }
+
+    #[test]
+    fn test_chip_reset() {
+        let mut sim = Simulator::new();
+        sim.poke(0x0006, 0x0F);
+
+        sim.write(&[0x80, 0x05, 0x07]).unwrap();
+        assert_eq!(sim.peek(0x0006), 0x08);
+        assert_eq!(sim.peek(0x0000), 0x01);
+        assert_eq!(sim.peek(0x0001), 0x01);
+
+        // The memory image matches the shadow copy after a reset, apart from
+        // the WAKEUP IRQs
+        let radio = Radio::reset_defaults();
+        for (info, reg) in REGISTER_MAP.iter().zip(radio.registers()) {
+            if info.name.ends_with("_irqs") {
+                continue;
+            }
+            let mut bytes = [0u8; 8];
+            for (i, byte) in bytes[..info.size].iter_mut().enumerate() {
+                *byte = sim.peek(info.address + i as u16);
+            }
+            assert_eq!(
+                u64::from_le_bytes(bytes),
+                reg.value().bits(),
+                "{}",
+                info.name
+            );
+        }
+    }
+
+    #[test]
+    fn test_driver_init() {
+        let mut sim = Simulator::new();
+        sim.poke(0x000B, 0x7F);
+        let mut driver = Driver::new(sim);
+
+        let config = InitConfig {
+            rf24_irqm: RfnIrqm::new().with_trxrdy(true),
+            ..Default::default()
+        };
+        let mut radio = Radio::new();
+        let device = driver.init(&mut radio, &mut NoopDelay, &config).unwrap();
+        assert_eq!(device.part, DevicePartNumber::AT86RF215);
+        assert_eq!(device.version, 0x03);
+
+        let sim = driver.release();
+        assert_eq!(sim.peek(0x0006), 0x08);
+        assert_eq!(sim.peek(0x000B), 0x02);
+        assert_eq!(sim.peek(0x0200), 0x02);
+
+        // The shadow matches the chip, with nothing left to flush
+        assert_eq!(radio.rf_iqifc1.value.into_bits(), 0x02);
+        assert_eq!(radio.rf24.irqm.value.into_bits(), 0x02);
+        assert!(radio.dirty_writes().is_empty());
+    }
+
+    #[test]
+    fn test_driver_init_wakeup_timeout() {
+        let sim = Simulator::with_config(SimConfig {
+            wakeup: false,
+            ..Default::default()
+        });
+        let mut driver = Driver::new(sim);
+
+        // The wait saturates at the timeout instead of overflowing
+        let config = InitConfig {
+            wakeup_timeout_us: u32::MAX,
+            poll_interval_us: u32::MAX / 2 + 1,
+            ..Default::default()
+        };
+        assert_eq!(
+            driver.init(&mut Radio::new(), &mut NoopDelay, &config),
+            Err(Error::WakeupTimeout { band: Band::Rf09 })
+        );
+    }
+}
@@ -0,0 +1,333 @@
+//! Linux spidev Transport
+//!
+//! Provides an `embedded_hal::spi::SpiDevice` over a `/dev/spidevX.Y`
+//! character device so the [`Driver`] can be used
+//! unchanged on Linux hosts. [`SpidevDevice::into_driver`] creates a driver
+//! that splits its transactions at the spidev maximum transfer length.
+//!
+//! # Response framing
+//!
+//! spidev transfers are full-duplex: the receive buffer is always as long as
+//! the transmit buffer. The AT86RF215 does not echo the 2-byte command header,
+//! but the 2 bytes clocked in while the header is sent are still present at
+//! the start of the response. Register data therefore starts at offset 2,
+//! which is what [`BulkReads::parse_responses`](crate::registers::BulkReads::parse_responses)
+//! expects.
+
+use std::io;
+use std::path::Path;
+use std::vec;
+use std::vec::Vec;
+
+use embedded_hal::spi::{self, ErrorKind, ErrorType, Operation, SpiDevice};
+use spidev::{SpiModeFlags, Spidev, SpidevOptions, SpidevTransfer};
+
+use crate::driver::Driver;
+
+/// Default maximum transfer length of the Linux spidev driver (`bufsiz`)
+pub const DEFAULT_MAX_TRANSFER_LEN: usize = 4096;
+
+/// spidev configuration applied when the device is opened
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpidevConfig {
+    /// SPI mode, the AT86RF215 uses mode 0
+    pub mode: SpiModeFlags,
+
+    /// SPI clock speed in Hz, the AT86RF215 supports up to 25MHz
+    pub speed_hz: u32,
+
+    /// Maximum number of bytes in a single chip select framed transfer,
+    /// at least [`MAX_COMMAND_LEN`](crate::registers::MAX_COMMAND_LEN) for
+    /// [`SpidevDevice::into_driver`]
+    pub max_transfer_len: usize,
+}
+
+impl Default for SpidevConfig {
+    fn default() -> Self {
+        Self {
+            mode: SpiModeFlags::SPI_MODE_0,
+            speed_hz: 5_000_000,
+            max_transfer_len: DEFAULT_MAX_TRANSFER_LEN,
+        }
+    }
+}
+
+/// spidev transport errors
+#[derive(Debug)]
+pub enum SpidevError {
+    /// Error reported by the spidev ioctl
+    Io(io::Error),
+
+    /// Transaction exceeds the configured maximum transfer length
+    TransferTooLong { len: usize, max: usize },
+
+    /// Delays within a transaction are not supported
+    UnsupportedDelay,
+}
+
+impl spi::Error for SpidevError {
+    fn kind(&self) -> ErrorKind {
+        ErrorKind::Other
+    }
+}
+
+impl From<io::Error> for SpidevError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+/// Low level access to an spidev character device
+///
+/// This is the seam between [`SpidevDevice`] and the kernel. It is implemented
+/// for [`Spidev`], tests substitute their own implementation to run without
+/// hardware.
+pub trait SpidevIo {
+    /// Applies mode and speed settings to the device.
+    fn configure(&mut self, options: &SpidevOptions) -> io::Result<()>;
+
+    /// Clocks out `tx` while filling `rx` in a single full-duplex transfer.
+    ///
+    /// Both buffers have the same length. The chip select is asserted for
+    /// the whole transfer.
+    fn transfer(&mut self, tx: &[u8], rx: &mut [u8]) -> io::Result<()>;
+}
+
+impl SpidevIo for Spidev {
+    fn configure(&mut self, options: &SpidevOptions) -> io::Result<()> {
+        Spidev::configure(self, options)
+    }
+
+    fn transfer(&mut self, tx: &[u8], rx: &mut [u8]) -> io::Result<()> {
+        Spidev::transfer(self, &mut SpidevTransfer::read_write(tx, rx))
+    }
+}
+
+/// `SpiDevice` implementation over spidev
+pub struct SpidevDevice<IO = Spidev> {
+    io: IO,
+    config: SpidevConfig,
+}
+
+impl SpidevDevice<Spidev> {
+    /// Opens and configures the spidev device at `path`, e.g. `/dev/spidev0.0`.
+    pub fn open<P: AsRef<Path>>(path: P, config: SpidevConfig) -> io::Result<Self> {
+        Self::new(Spidev::open(path)?, config)
+    }
+}
+
+impl<IO: SpidevIo> SpidevDevice<IO> {
+    /// Configures `io` and wraps it.
+    pub fn new(mut io: IO, config: SpidevConfig) -> io::Result<Self> {
+        let options = SpidevOptions::new()
+            .bits_per_word(8)
+            .max_speed_hz(config.speed_hz)
+            .lsb_first(false)
+            .mode(config.mode)
+            .build();
+        io.configure(&options)?;
+
+        Ok(Self { io, config })
+    }
+
+    pub fn config(&self) -> &SpidevConfig {
+        &self.config
+    }
+
+    /// Consumes the device and returns the underlying spidev handle.
+    pub fn release(self) -> IO {
+        self.io
+    }
+
+    /// Wraps the device in a [`Driver`] splitting its transactions at the
+    /// configured maximum transfer length.
+    ///
+    /// # Panics
+    /// Panics if `max_transfer_len` is shorter than
+    /// [`MAX_COMMAND_LEN`](crate::registers::MAX_COMMAND_LEN).
+    pub fn into_driver(self) -> Driver<Self> {
+        let max_transfer_len = self.config.max_transfer_len;
+        let mut driver = Driver::new(self);
+        driver.set_max_transfer_len(max_transfer_len);
+        driver
+    }
+}
+
+impl<IO> ErrorType for SpidevDevice<IO> {
+    type Error = SpidevError;
+}
+
+impl<IO: SpidevIo> SpiDevice for SpidevDevice<IO> {
+    /// Executes all operations in a single full-duplex transfer.
+    ///
+    /// The operations are concatenated so the chip select stays asserted
+    /// between them, then the received bytes are scattered back into the
+    /// read buffers.
+    fn transaction(&mut self, operations: &mut [Operation<'_, u8>]) -> Result<(), Self::Error> {
+        let mut tx = Vec::new();
+        for op in operations.iter() {
+            match op {
+                Operation::Read(buf) => tx.resize(tx.len() + buf.len(), 0x00),
+                Operation::Write(buf) => tx.extend_from_slice(buf),
+                Operation::Transfer(read, write) => {
+                    let len = read.len().max(write.len());
+                    tx.extend_from_slice(write);
+                    tx.resize(tx.len() + len - write.len(), 0x00);
+                }
+                Operation::TransferInPlace(buf) => tx.extend_from_slice(buf),
+                Operation::DelayNs(_) => return Err(SpidevError::UnsupportedDelay),
+            }
+        }
+
+        if tx.len() > self.config.max_transfer_len {
+            return Err(SpidevError::TransferTooLong {
+                len: tx.len(),
+                max: self.config.max_transfer_len,
+            });
+        }
+
+        let mut rx = vec![0x00; tx.len()];
+        self.io.transfer(&tx, &mut rx)?;
+
+        let mut offset = 0;
+        for op in operations.iter_mut() {
+            match op {
+                Operation::Read(buf) | Operation::TransferInPlace(buf) => {
+                    buf.copy_from_slice(&rx[offset..offset + buf.len()]);
+                    offset += buf.len();
+                }
+                Operation::Write(buf) => offset += buf.len(),
+                Operation::Transfer(read, write) => {
+                    read.copy_from_slice(&rx[offset..offset + read.len()]);
+                    offset += read.len().max(write.len());
+                }
+                Operation::DelayNs(_) => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::radio::Radio;
+    use crate::registers::*;
+
+    /// Fake spidev handle that records transfers and replays canned responses
+    #[derive(Default)]
+    struct FakeSpidev {
+        options: Option<SpidevOptions>,
+        sent: Vec<Vec<u8>>,
+        responses: Vec<Vec<u8>>,
+    }
+
+    impl SpidevIo for FakeSpidev {
+        fn configure(&mut self, options: &SpidevOptions) -> io::Result<()> {
+            self.options = Some(*options);
+            Ok(())
+        }
+
+        fn transfer(&mut self, tx: &[u8], rx: &mut [u8]) -> io::Result<()> {
+            assert_eq!(tx.len(), rx.len());
+            self.sent.push(tx.to_vec());
+            if !self.responses.is_empty() {
+                rx.copy_from_slice(&self.responses.remove(0));
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_configure() {
+        let config = SpidevConfig {
+            mode: SpiModeFlags::SPI_MODE_0,
+            speed_hz: 10_000_000,
+            max_transfer_len: 255,
+        };
+        let spi = SpidevDevice::new(FakeSpidev::default(), config).unwrap();
+
+        let options = spi.release().options.unwrap();
+        assert_eq!(options.max_speed_hz, Some(10_000_000));
+        assert_eq!(options.bits_per_word, Some(8));
+        assert_eq!(options.spi_mode, Some(SpiModeFlags::SPI_MODE_0));
+    }
+
+    #[test]
+    fn test_driver_read_response_framing() {
+        let fake = FakeSpidev {
+            // 2 bytes clocked in during the header, followed by the data
+            responses: vec![vec![0x00, 0x00, 0x34, 0x03]],
+            ..Default::default()
+        };
+        let spi = SpidevDevice::new(fake, SpidevConfig::default()).unwrap();
+        let mut driver = Driver::new(spi);
+        let mut radio = Radio::new();
+
+        let mut reads = BulkReads::new();
+        reads.add(&mut radio.rf_pn);
+        reads.add(&mut radio.rf_vn);
+        driver.read_bulk(&mut reads).unwrap();
+
+        assert_eq!(radio.rf_pn.value.pn(), DevicePartNumber::AT86RF215);
+        assert_eq!(radio.rf_vn.value.vn(), 0x03);
+
+        let fake = driver.release().release();
+        assert_eq!(fake.sent, vec![vec![0x00, 0x0D, 0x00, 0x00]]);
+    }
+
+    #[test]
+    fn test_transaction_is_single_transfer() {
+        let fake = FakeSpidev {
+            responses: vec![vec![0x00, 0x00, 0xAA, 0xBB]],
+            ..Default::default()
+        };
+        let mut spi = SpidevDevice::new(fake, SpidevConfig::default()).unwrap();
+
+        let mut data = [0u8; 2];
+        spi.transaction(&mut [Operation::Write(&[0x03, 0x91]), Operation::Read(&mut data)])
+            .unwrap();
+        assert_eq!(data, [0xAA, 0xBB]);
+
+        let fake = spi.release();
+        assert_eq!(fake.sent, vec![vec![0x03, 0x91, 0x00, 0x00]]);
+    }
+
+    #[test]
+    fn test_max_transfer_len() {
+        let config = SpidevConfig {
+            max_transfer_len: 4,
+            ..Default::default()
+        };
+        let mut spi = SpidevDevice::new(FakeSpidev::default(), config).unwrap();
+
+        let result = spi.write(&[0x80, 0x06, 0x00, 0x00, 0x00]);
+        assert!(matches!(
+            result,
+            Err(SpidevError::TransferTooLong { len: 5, max: 4 })
+        ));
+        assert!(spi.release().sent.is_empty());
+    }
+
+    #[test]
+    fn test_driver_splits_at_max_transfer_len() {
+        let config = SpidevConfig {
+            max_transfer_len: 12,
+            ..Default::default()
+        };
+        let spi = SpidevDevice::new(FakeSpidev::default(), config).unwrap();
+        let mut driver = spi.into_driver();
+        assert_eq!(driver.max_transfer_len(), 12);
+
+        driver.write_buffer(0x2800, &[0xAA; 25]).unwrap();
+
+        let fake = driver.release().release();
+        let lens: Vec<_> = fake.sent.iter().map(Vec::len).collect();
+        assert_eq!(lens, [12, 12, 7]);
+    }
+}